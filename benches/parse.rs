@@ -52,6 +52,18 @@ fn bench_parse(c: &mut criterion::Criterion) {
         });
     });
 
+    c.bench_function("decode big coords", |bencher| {
+        let wkb::reader::Wkb::MultiPolygon(multi_polygon) =
+            wkb::reader::read_wkb(&big_wkb).unwrap()
+        else {
+            unreachable!()
+        };
+        bencher.iter(|| {
+            let mut coords = Vec::new();
+            multi_polygon.flat_coords().extend_interleaved(&mut coords);
+        });
+    });
+
     c.bench_function("encode small", |bencher| {
         bencher.iter(|| {
             let mut buf = Vec::new();
@@ -0,0 +1,116 @@
+//! The bounds of the coordinates of a geometry.
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
+};
+
+/// The range of the x, y, z and m values of a geometry
+pub(crate) struct Bounds {
+    min: [f64; 4],
+    max: [f64; 4],
+}
+
+impl Bounds {
+    pub(crate) fn new() -> Self {
+        Self {
+            min: [f64::INFINITY; 4],
+            max: [f64::NEG_INFINITY; 4],
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.min[0] > self.max[0]
+    }
+
+    pub(crate) fn add_coord(&mut self, coord: &impl CoordTrait<T: Into<f64>>) {
+        for (n, i) in value_indices(coord.dim()).iter().enumerate() {
+            self.add_value(*i, coord.nth_or_panic(n).into());
+        }
+    }
+
+    /// Add a value of dimension `i`, where x, y, z and m are 0 to 3
+    pub(crate) fn add_value(&mut self, i: usize, value: f64) {
+        // NaN values, such as those of an empty point, are ignored
+        self.min[i] = self.min[i].min(value);
+        self.max[i] = self.max[i].max(value);
+    }
+
+    fn add_line_string(&mut self, geom: &impl LineStringTrait<T: Into<f64>>) {
+        for coord in geom.coords() {
+            self.add_coord(&coord);
+        }
+    }
+
+    fn add_polygon(&mut self, geom: &impl PolygonTrait<T: Into<f64>>) {
+        if let Some(exterior) = geom.exterior() {
+            self.add_line_string(&exterior);
+        }
+    }
+
+    pub(crate) fn add_geometry(&mut self, geom: &impl GeometryTrait<T: Into<f64>>) {
+        use GeometryType::*;
+        match geom.as_type() {
+            Point(p) => {
+                if let Some(coord) = p.coord() {
+                    self.add_coord(&coord);
+                }
+            }
+            LineString(ls) => self.add_line_string(ls),
+            Polygon(p) => self.add_polygon(p),
+            MultiPoint(mp) => {
+                for point in mp.points() {
+                    if let Some(coord) = point.coord() {
+                        self.add_coord(&coord);
+                    }
+                }
+            }
+            MultiLineString(ml) => {
+                for line_string in ml.line_strings() {
+                    self.add_line_string(&line_string);
+                }
+            }
+            MultiPolygon(mp) => {
+                for polygon in mp.polygons() {
+                    self.add_polygon(&polygon);
+                }
+            }
+            GeometryCollection(gc) => {
+                for geometry in gc.geometries() {
+                    self.add_geometry(&geometry);
+                }
+            }
+            Rect(r) => {
+                self.add_coord(&r.min());
+                self.add_coord(&r.max());
+            }
+            Triangle(tri) => {
+                for coord in tri.coords() {
+                    self.add_coord(&coord);
+                }
+            }
+            Line(line) => {
+                for coord in line.coords() {
+                    self.add_coord(&coord);
+                }
+            }
+        }
+    }
+
+    /// The minimum and maximum of dimension `i`, where x, y, z and m are 0 to 3, or `None` if no
+    /// coordinates have a value in that dimension
+    pub(crate) fn range(&self, i: usize) -> Option<(f64, f64)> {
+        (self.min[i] <= self.max[i]).then_some((self.min[i], self.max[i]))
+    }
+}
+
+/// The index into x, y, z and m of each value of a coordinate with dimension `dim`
+pub(crate) fn value_indices(dim: Dimensions) -> &'static [usize] {
+    match dim {
+        Dimensions::Xyz | Dimensions::Unknown(3) => &[0, 1, 2],
+        Dimensions::Xym => &[0, 1, 3],
+        Dimensions::Xyzm | Dimensions::Unknown(4) => &[0, 1, 2, 3],
+        _ => &[0, 1],
+    }
+}
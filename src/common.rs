@@ -1,9 +1,7 @@
-use std::io::Cursor;
-
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::error::{WKBError, WKBResult};
+use crate::reader::util::read_header;
 
 /// Bit flag for EWKB Geometry with a z coordinate
 const EWKB_FLAG_Z: u32 = 0x80000000;
@@ -13,11 +11,15 @@ const EWKB_FLAG_M: u32 = 0x40000000;
 const EWKB_FLAG_SRID: u32 = 0x20000000;
 
 /// Supported WKB dimensions
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WKBDimension {
+    /// Two-dimensional coordinates
     Xy,
+    /// Three-dimensional coordinates with a z value
     Xyz,
+    /// Three-dimensional coordinates with an m value
     Xym,
+    /// Four-dimensional coordinates with z and m values
     Xyzm,
 }
 
@@ -31,6 +33,24 @@ impl WKBDimension {
         }
     }
 
+    pub(crate) fn has_z(&self) -> bool {
+        matches!(self, Self::Xyz | Self::Xyzm)
+    }
+
+    pub(crate) fn has_m(&self) -> bool {
+        matches!(self, Self::Xym | Self::Xyzm)
+    }
+
+    /// The dimension with the given z and m values
+    pub(crate) fn from_flags(has_z: bool, has_m: bool) -> Self {
+        match (has_z, has_m) {
+            (false, false) => Self::Xy,
+            (true, false) => Self::Xyz,
+            (false, true) => Self::Xym,
+            (true, true) => Self::Xyzm,
+        }
+    }
+
     pub(crate) fn size(&self) -> usize {
         match self {
             Self::Xy => 2,
@@ -92,20 +112,35 @@ impl WKBGeometryCode {
         self.0 & EWKB_FLAG_SRID == EWKB_FLAG_SRID
     }
 
+    /// Whether this code has any of the extended WKB flags
+    pub(crate) fn is_extended(&self) -> bool {
+        self.0 & (EWKB_FLAG_Z | EWKB_FLAG_M | EWKB_FLAG_SRID) != 0
+    }
+
     pub fn get_type(&self) -> WKBResult<WKBType> {
         let code = self.0;
         let mut dim = WKBDimension::Xy;
 
+        // Remove any EWKB flags to get the ISO code
+        let iso_code = code & !(EWKB_FLAG_Z | EWKB_FLAG_M | EWKB_FLAG_SRID);
+
         // For ISO WKB:
         // Values 1, 2, 3 are 2D,
         // 1001, 1002, 1003 are XYZ,
         // 2001 etc are XYM,
         // 3001 etc are XYZM
-        match code / 1000 {
+        match iso_code / 1000 {
+            0 => (),
             1 => dim = WKBDimension::Xyz,
             2 => dim = WKBDimension::Xym,
             3 => dim = WKBDimension::Xyzm,
-            _ => (),
+            _ => {
+                return Err(WKBError::UnsupportedDimension {
+                    code,
+                    offset: 0,
+                    path: vec![],
+                })
+            }
         };
 
         // For extended WKB, higher dimensions are provided via bit flags
@@ -119,7 +154,7 @@ impl WKBGeometryCode {
             _ => (),
         }
 
-        let typ = match code & 0x7 {
+        let typ = match iso_code % 1000 {
             1 => WKBType::Point(dim),
             2 => WKBType::LineString(dim),
             3 => WKBType::Polygon(dim),
@@ -127,11 +162,20 @@ impl WKBGeometryCode {
             5 => WKBType::MultiLineString(dim),
             6 => WKBType::MultiPolygon(dim),
             7 => WKBType::GeometryCollection(dim),
+            8 => WKBType::CircularString(dim),
+            9 => WKBType::CompoundCurve(dim),
+            10 => WKBType::CurvePolygon(dim),
+            11 => WKBType::MultiCurve(dim),
+            12 => WKBType::MultiSurface(dim),
+            15 => WKBType::PolyhedralSurface(dim),
+            16 => WKBType::Tin(dim),
+            17 => WKBType::Triangle(dim),
             _ => {
-                return Err(WKBError::General(format!(
-                    "WKB type code out of range. Got: {}",
-                    code
-                )))
+                return Err(WKBError::UnknownGeometryCode {
+                    code,
+                    offset: 0,
+                    path: vec![],
+                })
             }
         };
         Ok(typ)
@@ -139,7 +183,7 @@ impl WKBGeometryCode {
 }
 
 /// The various WKB types supported by this crate
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WKBType {
     /// A WKB Point
     Point(WKBDimension),
@@ -155,24 +199,71 @@ pub enum WKBType {
     MultiPolygon(WKBDimension),
     /// A WKB GeometryCollection
     GeometryCollection(WKBDimension),
+    /// A WKB CircularString
+    CircularString(WKBDimension),
+    /// A WKB CompoundCurve
+    CompoundCurve(WKBDimension),
+    /// A WKB CurvePolygon
+    CurvePolygon(WKBDimension),
+    /// A WKB MultiCurve
+    MultiCurve(WKBDimension),
+    /// A WKB MultiSurface
+    MultiSurface(WKBDimension),
+    /// A WKB PolyhedralSurface
+    PolyhedralSurface(WKBDimension),
+    /// A WKB TIN (triangulated irregular network)
+    Tin(WKBDimension),
+    /// A WKB Triangle
+    Triangle(WKBDimension),
 }
 
 impl WKBType {
     /// Construct from a byte slice representing a WKB geometry
     pub fn from_buffer(buf: &[u8]) -> WKBResult<Self> {
-        let mut reader = Cursor::new(buf);
-        let byte_order = reader.read_u8().unwrap();
-        let geometry_code = match byte_order {
-            0 => reader.read_u32::<BigEndian>().unwrap(),
-            1 => reader.read_u32::<LittleEndian>().unwrap(),
-            other => {
-                return Err(WKBError::General(format!(
-                    "Unexpected byte order: {}",
-                    other
-                )))
-            }
-        };
-        WKBGeometryCode(geometry_code).get_type()
+        let (_, wkb_type) = read_header(buf, 0)?;
+        Ok(wkb_type)
+    }
+
+    /// The dimension of this type
+    pub fn dimension(&self) -> WKBDimension {
+        match self {
+            Self::Point(dim)
+            | Self::LineString(dim)
+            | Self::Polygon(dim)
+            | Self::MultiPoint(dim)
+            | Self::MultiLineString(dim)
+            | Self::MultiPolygon(dim)
+            | Self::GeometryCollection(dim)
+            | Self::CircularString(dim)
+            | Self::CompoundCurve(dim)
+            | Self::CurvePolygon(dim)
+            | Self::MultiCurve(dim)
+            | Self::MultiSurface(dim)
+            | Self::PolyhedralSurface(dim)
+            | Self::Tin(dim)
+            | Self::Triangle(dim) => *dim,
+        }
+    }
+
+    /// This type with the dimension `dim`
+    pub(crate) fn with_dimension(&self, dim: WKBDimension) -> Self {
+        match self {
+            Self::Point(_) => Self::Point(dim),
+            Self::LineString(_) => Self::LineString(dim),
+            Self::Polygon(_) => Self::Polygon(dim),
+            Self::MultiPoint(_) => Self::MultiPoint(dim),
+            Self::MultiLineString(_) => Self::MultiLineString(dim),
+            Self::MultiPolygon(_) => Self::MultiPolygon(dim),
+            Self::GeometryCollection(_) => Self::GeometryCollection(dim),
+            Self::CircularString(_) => Self::CircularString(dim),
+            Self::CompoundCurve(_) => Self::CompoundCurve(dim),
+            Self::CurvePolygon(_) => Self::CurvePolygon(dim),
+            Self::MultiCurve(_) => Self::MultiCurve(dim),
+            Self::MultiSurface(_) => Self::MultiSurface(dim),
+            Self::PolyhedralSurface(_) => Self::PolyhedralSurface(dim),
+            Self::Tin(_) => Self::Tin(dim),
+            Self::Triangle(_) => Self::Triangle(dim),
+        }
     }
 
     pub fn as_geometry_code(&self) -> WKBGeometryCode {
@@ -184,9 +275,55 @@ impl WKBType {
             Self::MultiLineString(dim) => 5 + dim.as_u32_offset(),
             Self::MultiPolygon(dim) => 6 + dim.as_u32_offset(),
             Self::GeometryCollection(dim) => 7 + dim.as_u32_offset(),
+            Self::CircularString(dim) => 8 + dim.as_u32_offset(),
+            Self::CompoundCurve(dim) => 9 + dim.as_u32_offset(),
+            Self::CurvePolygon(dim) => 10 + dim.as_u32_offset(),
+            Self::MultiCurve(dim) => 11 + dim.as_u32_offset(),
+            Self::MultiSurface(dim) => 12 + dim.as_u32_offset(),
+            Self::PolyhedralSurface(dim) => 15 + dim.as_u32_offset(),
+            Self::Tin(dim) => 16 + dim.as_u32_offset(),
+            Self::Triangle(dim) => 17 + dim.as_u32_offset(),
         };
         WKBGeometryCode(code)
     }
+
+    /// Whether this is one of the SQL/MM curve types, which can only be read with
+    /// [`read_curved_wkb`][crate::reader::read_curved_wkb]
+    pub fn is_curve(&self) -> bool {
+        matches!(
+            self,
+            Self::CircularString(_)
+                | Self::CompoundCurve(_)
+                | Self::CurvePolygon(_)
+                | Self::MultiCurve(_)
+                | Self::MultiSurface(_)
+        )
+    }
+}
+
+impl WKBType {
+    /// The geometry code of this type in extended WKB, with the dimension in the high bit flags
+    pub fn as_ewkb_geometry_code(&self, has_srid: bool) -> WKBGeometryCode {
+        let dim = self.dimension();
+        let mut code = self.as_geometry_code().0 - dim.as_u32_offset();
+        if matches!(dim, WKBDimension::Xyz | WKBDimension::Xyzm) {
+            code |= EWKB_FLAG_Z;
+        }
+        if matches!(dim, WKBDimension::Xym | WKBDimension::Xyzm) {
+            code |= EWKB_FLAG_M;
+        }
+        if has_srid {
+            code |= EWKB_FLAG_SRID;
+        }
+        WKBGeometryCode(code)
+    }
+
+    /// The geometry code of this type in OGC 1.1 WKB, which drops the dimension, or `None` if
+    /// OGC 1.1 WKB has no such type
+    pub(crate) fn as_ogc11_geometry_code(&self) -> Option<WKBGeometryCode> {
+        let code = self.as_geometry_code().0 - self.dimension().as_u32_offset();
+        (code <= 7).then_some(WKBGeometryCode(code))
+    }
 }
 
 impl From<WKBType> for u32 {
@@ -195,8 +332,57 @@ impl From<WKBType> for u32 {
     }
 }
 
+impl From<WKBGeometryCode> for u32 {
+    fn from(value: WKBGeometryCode) -> Self {
+        value.0
+    }
+}
+
+/// The flavor of WKB to write
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WKBFlavor {
+    /// ISO WKB, where the dimension is encoded as an offset to the geometry code, e.g. `1001` for
+    /// a Point Z.
+    #[default]
+    Iso,
+    /// Extended WKB (EWKB) as used by PostGIS, where the dimension is encoded in high bit flags
+    /// of the geometry code and an SRID may follow the geometry code.
+    ///
+    /// The SRID is only written for the top-level geometry, not for any nested parts.
+    Extended {
+        /// The SRID to embed in the geometry
+        srid: Option<i32>,
+    },
+    /// WKB as defined by OGC Simple Features 1.1, which only has two-dimensional Points,
+    /// LineStrings, Polygons, MultiPoints, MultiLineStrings, MultiPolygons and
+    /// GeometryCollections.
+    ///
+    /// Any z and m values are dropped and Triangles are written as Polygons. Other geometry types
+    /// return an error.
+    Ogc11,
+}
+
+impl WKBFlavor {
+    /// The flavor to use for parts nested in a geometry written with this flavor
+    pub(crate) fn nested(&self) -> Self {
+        match self {
+            Self::Iso => Self::Iso,
+            Self::Extended { .. } => Self::Extended { srid: None },
+            Self::Ogc11 => Self::Ogc11,
+        }
+    }
+
+    /// The SRID embedded with this flavor, if any
+    pub(crate) fn srid(&self) -> Option<i32> {
+        match self {
+            Self::Iso | Self::Ogc11 => None,
+            Self::Extended { srid } => *srid,
+        }
+    }
+}
+
 /// Endianness
-#[derive(Debug, Clone, Copy, Default, TryFromPrimitive, IntoPrimitive)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum Endianness {
     BigEndian = 0,
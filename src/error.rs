@@ -4,7 +4,14 @@ use std::borrow::Cow;
 use std::fmt::Debug;
 use thiserror::Error;
 
+use crate::common::{WKBDimension, WKBType};
+
 /// Enum with all errors in this crate.
+///
+/// Errors that arise from malformed input carry the byte `offset` into the buffer where the
+/// problem was found and the `path` to the nested geometry that contains it. The path is the
+/// sequence of part indices (points, line strings, polygons, rings or geometries) from the
+/// outermost geometry inward, and is empty for the top-level geometry.
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum WKBError {
@@ -20,10 +27,247 @@ pub enum WKBError {
     #[error("General error: {0}")]
     General(String),
 
+    /// The buffer ended before the geometry was complete.
+    #[error("Unexpected end of buffer: needed {needed} bytes at offset {offset} (path {path:?})")]
+    UnexpectedEof {
+        /// The offset of the read that could not be completed
+        offset: u64,
+        /// The number of bytes that read needed
+        needed: u64,
+        /// The path to the nested geometry being read
+        path: Vec<usize>,
+    },
+
+    /// The byte order byte was neither `0` (big endian) nor `1` (little endian).
+    #[error("Invalid byte order {byte} at offset {offset} (path {path:?})")]
+    InvalidByteOrder {
+        /// The invalid byte
+        byte: u8,
+        /// The offset of the byte order byte
+        offset: u64,
+        /// The path to the nested geometry being read
+        path: Vec<usize>,
+    },
+
+    /// The geometry code does not describe a known geometry type.
+    #[error("Unknown geometry code {code} at offset {offset} (path {path:?})")]
+    UnknownGeometryCode {
+        /// The geometry code
+        code: u32,
+        /// The offset of the start of the geometry
+        offset: u64,
+        /// The path to the nested geometry being read
+        path: Vec<usize>,
+    },
+
+    /// The geometry code describes a dimension that is not supported.
+    #[error("Unsupported dimension in geometry code {code} at offset {offset} (path {path:?})")]
+    UnsupportedDimension {
+        /// The geometry code
+        code: u32,
+        /// The offset of the start of the geometry
+        offset: u64,
+        /// The path to the nested geometry being read
+        path: Vec<usize>,
+    },
+
+    /// A nested geometry has a different dimension than its parent.
+    #[error(
+        "Nested geometry has dimension {child:?} but its parent has dimension {parent:?} at \
+         offset {offset} (path {path:?})"
+    )]
+    DimensionMismatch {
+        /// The dimension of the parent geometry
+        parent: WKBDimension,
+        /// The dimension of the nested geometry
+        child: WKBDimension,
+        /// The offset of the start of the nested geometry
+        offset: u64,
+        /// The path to the nested geometry
+        path: Vec<usize>,
+    },
+
+    /// A nested geometry has a type that is not allowed in its parent.
+    #[error("Expected {expected:?} but found {found:?} at offset {offset} (path {path:?})")]
+    UnexpectedGeometryType {
+        /// The type allowed by the parent geometry
+        expected: WKBType,
+        /// The type of the nested geometry
+        found: WKBType,
+        /// The offset of the start of the nested geometry
+        offset: u64,
+        /// The path to the nested geometry
+        path: Vec<usize>,
+    },
+
+    /// A Point within a MultiPoint has an SRID of its own.
+    #[error("Unexpected SRID {srid} in nested Point at offset {offset} (path {path:?})")]
+    UnexpectedSrid {
+        /// The SRID of the nested Point
+        srid: i32,
+        /// The offset of the start of the nested Point
+        offset: u64,
+        /// The path to the nested Point
+        path: Vec<usize>,
+    },
+
+    /// A non-empty Triangle does not have exactly one closed ring of four points.
+    #[error(
+        "Triangle with {num_rings} rings and {num_points} points (closed: {closed}) at offset \
+         {offset} (path {path:?})"
+    )]
+    InvalidTriangle {
+        /// The number of rings in the Triangle
+        num_rings: usize,
+        /// The number of points in the first ring of the Triangle, or zero if it has no rings
+        num_points: usize,
+        /// Whether the first and last points of the first ring are equal, or `true` if it has no
+        /// points
+        closed: bool,
+        /// The offset of the start of the Triangle
+        offset: u64,
+        /// The path to the Triangle
+        path: Vec<usize>,
+    },
+
+    /// Geometries are nested more than [`MAX_NESTING_DEPTH`][crate::reader::MAX_NESTING_DEPTH]
+    /// levels deep.
+    #[error(
+        "Geometry nested more than {limit} levels deep at offset {offset} (path {path:?})",
+        limit = crate::reader::MAX_NESTING_DEPTH
+    )]
+    TooDeeplyNested {
+        /// The offset of the start of the geometry beyond the limit
+        offset: u64,
+        /// The path to the geometry beyond the limit
+        path: Vec<usize>,
+    },
+
+    /// A curve geometry was found where only linear geometries can be read.
+    ///
+    /// Use [`read_curved_wkb`][crate::reader::read_curved_wkb] to read curve geometries.
+    #[error("Curve geometry {found:?} cannot be read as a linear geometry at offset {offset} (path {path:?})")]
+    CurveGeometry {
+        /// The type of the curve geometry
+        found: WKBType,
+        /// The offset of the start of the curve geometry
+        offset: u64,
+        /// The path to the curve geometry
+        path: Vec<usize>,
+    },
+
+    /// The buffer continued after the end of the geometry.
+    #[error("{trailing} trailing bytes after geometry ending at offset {offset}")]
+    TrailingBytes {
+        /// The offset where the geometry ended
+        offset: u64,
+        /// The number of bytes after the end of the geometry
+        trailing: u64,
+    },
+
+    /// A hex-encoded geometry has a character that is not a hex digit, or an odd number of digits.
+    #[error("Invalid or missing hex digit at offset {offset}")]
+    InvalidHex {
+        /// The offset into the hex string of the invalid or missing digit
+        offset: u64,
+    },
+
+    /// The header of a container around WKB, such as a GeoPackage geometry blob, is invalid.
+    #[error("Invalid header at offset {offset}: {reason}")]
+    InvalidHeader {
+        /// What is wrong with the header
+        reason: Cow<'static, str>,
+        /// The offset of the invalid field
+        offset: u64,
+    },
+
+    /// A TWKB varint is longer than the largest 64-bit value.
+    #[error("Invalid varint at offset {offset} (path {path:?})")]
+    InvalidVarint {
+        /// The offset of the start of the varint
+        offset: u64,
+        /// The path to the nested geometry being read
+        path: Vec<usize>,
+    },
+
     /// [std::io::Error]
     #[error(transparent)]
     IOError(#[from] std::io::Error),
 }
 
+impl WKBError {
+    /// The byte offset into the buffer where this error was found, if known.
+    ///
+    /// For [`WKBError::InvalidHex`] this is the offset into the hex string.
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            Self::UnexpectedEof { offset, .. }
+            | Self::InvalidByteOrder { offset, .. }
+            | Self::UnknownGeometryCode { offset, .. }
+            | Self::UnsupportedDimension { offset, .. }
+            | Self::DimensionMismatch { offset, .. }
+            | Self::UnexpectedGeometryType { offset, .. }
+            | Self::UnexpectedSrid { offset, .. }
+            | Self::InvalidTriangle { offset, .. }
+            | Self::TooDeeplyNested { offset, .. }
+            | Self::CurveGeometry { offset, .. }
+            | Self::TrailingBytes { offset, .. }
+            | Self::InvalidHex { offset }
+            | Self::InvalidVarint { offset, .. }
+            | Self::InvalidHeader { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// The path to the nested geometry where this error was found, if known.
+    ///
+    /// An empty path refers to the top-level geometry.
+    pub fn path(&self) -> Option<&[usize]> {
+        match self {
+            Self::UnexpectedEof { path, .. }
+            | Self::InvalidByteOrder { path, .. }
+            | Self::UnknownGeometryCode { path, .. }
+            | Self::UnsupportedDimension { path, .. }
+            | Self::DimensionMismatch { path, .. }
+            | Self::UnexpectedGeometryType { path, .. }
+            | Self::UnexpectedSrid { path, .. }
+            | Self::InvalidTriangle { path, .. }
+            | Self::TooDeeplyNested { path, .. }
+            | Self::CurveGeometry { path, .. }
+            | Self::InvalidVarint { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Set the offset of an error that was created without knowing its position in the buffer.
+    pub(crate) fn at_offset(mut self, new_offset: u64) -> Self {
+        match &mut self {
+            Self::UnknownGeometryCode { offset, .. }
+            | Self::UnsupportedDimension { offset, .. } => *offset = new_offset,
+            _ => (),
+        }
+        self
+    }
+
+    /// Record that this error occurred inside the part at `index` of the enclosing geometry.
+    pub(crate) fn in_part(mut self, index: usize) -> Self {
+        match &mut self {
+            Self::UnexpectedEof { path, .. }
+            | Self::InvalidByteOrder { path, .. }
+            | Self::UnknownGeometryCode { path, .. }
+            | Self::UnsupportedDimension { path, .. }
+            | Self::DimensionMismatch { path, .. }
+            | Self::UnexpectedGeometryType { path, .. }
+            | Self::UnexpectedSrid { path, .. }
+            | Self::InvalidTriangle { path, .. }
+            | Self::TooDeeplyNested { path, .. }
+            | Self::CurveGeometry { path, .. }
+            | Self::InvalidVarint { path, .. } => path.insert(0, index),
+            _ => (),
+        }
+        self
+    }
+}
+
 /// Crate-specific result type.
 pub type WKBResult<T> = std::result::Result<T, WKBError>;
@@ -0,0 +1,101 @@
+//! Read and write [GeoPackage binary][spec] geometry blobs.
+//!
+//! Each geometry in a GeoPackage feature table is stored as a header, with the spatial reference
+//! system id and an optional envelope, followed by standard ISO WKB.
+//!
+//! [spec]: https://www.geopackage.org/spec/#gpb_format
+
+use crate::common::WKBDimension;
+
+mod reader;
+mod writer;
+
+pub use reader::{read_gpkg_geometry, GpkgGeometry};
+pub use writer::{gpkg_geometry_size, write_gpkg_geometry};
+
+/// The magic bytes at the start of each GeoPackage geometry
+const MAGIC: [u8; 2] = *b"GP";
+/// The version byte for version 1 of the GeoPackage binary format
+const VERSION_1: u8 = 0;
+
+/// Bit flags of the header flags byte
+const FLAG_LITTLE_ENDIAN: u8 = 0x01;
+const FLAG_EMPTY: u8 = 0x10;
+const FLAG_EXTENDED: u8 = 0x20;
+/// The envelope contents indicator occupies bits 1 to 3
+const ENVELOPE_SHIFT: u8 = 1;
+const ENVELOPE_MASK: u8 = 0x07;
+
+/// The envelope contents indicator code for an envelope of the given dimension
+fn envelope_code(dim: Option<WKBDimension>) -> u8 {
+    match dim {
+        None => 0,
+        Some(WKBDimension::Xy) => 1,
+        Some(WKBDimension::Xyz) => 2,
+        Some(WKBDimension::Xym) => 3,
+        Some(WKBDimension::Xyzm) => 4,
+    }
+}
+
+/// The number of bytes of an envelope of the given dimension
+fn envelope_size(dim: Option<WKBDimension>) -> usize {
+    dim.map_or(0, |dim| 16 * dim.size())
+}
+
+/// The bounding box stored in the header of a GeoPackage geometry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpkgEnvelope {
+    /// The minimum x value
+    pub min_x: f64,
+    /// The maximum x value
+    pub max_x: f64,
+    /// The minimum y value
+    pub min_y: f64,
+    /// The maximum y value
+    pub max_y: f64,
+    /// The minimum and maximum z values, for an XYZ or XYZM envelope
+    pub z: Option<(f64, f64)>,
+    /// The minimum and maximum m values, for an XYM or XYZM envelope
+    pub m: Option<(f64, f64)>,
+}
+
+impl GpkgEnvelope {
+    /// The form of this envelope: XY, XYZ, XYM or XYZM
+    pub fn dimension(&self) -> WKBDimension {
+        match (self.z.is_some(), self.m.is_some()) {
+            (false, false) => WKBDimension::Xy,
+            (true, false) => WKBDimension::Xyz,
+            (false, true) => WKBDimension::Xym,
+            (true, true) => WKBDimension::Xyzm,
+        }
+    }
+
+    /// The values of this envelope in the order they are stored
+    fn values(&self) -> Vec<f64> {
+        let mut values = vec![self.min_x, self.max_x, self.min_y, self.max_y];
+        for (min, max) in [self.z, self.m].into_iter().flatten() {
+            values.push(min);
+            values.push(max);
+        }
+        values
+    }
+
+    /// Construct an envelope from its values in the order they are stored
+    fn from_values(dim: WKBDimension, values: &[f64]) -> Self {
+        let pair = |i: usize| (values[i], values[i + 1]);
+        let (z, m) = match dim {
+            WKBDimension::Xy => (None, None),
+            WKBDimension::Xyz => (Some(pair(4)), None),
+            WKBDimension::Xym => (None, Some(pair(4))),
+            WKBDimension::Xyzm => (Some(pair(4)), Some(pair(6))),
+        };
+        Self {
+            min_x: values[0],
+            max_x: values[1],
+            min_y: values[2],
+            max_y: values[3],
+            z,
+            m,
+        }
+    }
+}
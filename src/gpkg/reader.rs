@@ -0,0 +1,137 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::WKBDimension;
+use crate::error::{WKBError, WKBResult};
+use crate::gpkg::*;
+use crate::reader::util::{check_bounds, read_u32_at, read_u8_at};
+use crate::reader::Wkb;
+use crate::Endianness;
+
+/// A geometry read from a GeoPackage binary blob
+#[derive(Debug, Clone)]
+pub struct GpkgGeometry<'a> {
+    srs_id: i32,
+    envelope: Option<GpkgEnvelope>,
+    is_empty: bool,
+    is_extended: bool,
+    header_size: usize,
+    wkb: &'a [u8],
+    geometry: Wkb<'a>,
+}
+
+impl<'a> GpkgGeometry<'a> {
+    /// The id of the spatial reference system of this geometry, from `gpkg_spatial_ref_sys`
+    pub fn srs_id(&self) -> i32 {
+        self.srs_id
+    }
+
+    /// The envelope stored in the header, if any
+    pub fn envelope(&self) -> Option<&GpkgEnvelope> {
+        self.envelope.as_ref()
+    }
+
+    /// Whether the header marks this geometry as empty
+    pub fn is_empty(&self) -> bool {
+        self.is_empty
+    }
+
+    /// Whether this geometry uses the GeoPackage extended geometry type encoding
+    pub fn is_extended(&self) -> bool {
+        self.is_extended
+    }
+
+    /// The number of bytes of the GeoPackage header before the WKB geometry
+    pub fn header_size(&self) -> usize {
+        self.header_size
+    }
+
+    /// The WKB bytes of the geometry, without the GeoPackage header
+    pub fn wkb(&self) -> &'a [u8] {
+        self.wkb
+    }
+
+    /// The geometry
+    pub fn geometry(&self) -> &Wkb<'a> {
+        &self.geometry
+    }
+}
+
+/// Read a geometry from a GeoPackage binary blob.
+///
+/// This parses the GeoPackage header and the standard WKB geometry that follows it. Byte offsets
+/// in errors are from the start of the blob, including the header.
+///
+/// ```
+/// use wkb::gpkg::{read_gpkg_geometry, write_gpkg_geometry};
+///
+/// let point = geo_types::Point::new(1., 2.);
+/// let mut blob = Vec::new();
+/// write_gpkg_geometry(&mut blob, &point, 4326, None, Default::default()).unwrap();
+///
+/// let geometry = read_gpkg_geometry(&blob).unwrap();
+/// assert_eq!(geometry.srs_id(), 4326);
+/// assert_eq!(geometry.envelope(), None);
+/// ```
+pub fn read_gpkg_geometry(buf: &[u8]) -> WKBResult<GpkgGeometry<'_>> {
+    check_bounds(buf, 0, 4)?;
+    if buf[0..2] != MAGIC {
+        return Err(WKBError::InvalidHeader {
+            reason: "GeoPackage geometry does not start with \"GP\"".into(),
+            offset: 0,
+        });
+    }
+    if buf[2] != VERSION_1 {
+        return Err(WKBError::InvalidHeader {
+            reason: format!("Unsupported GeoPackage binary version {}", buf[2]).into(),
+            offset: 2,
+        });
+    }
+
+    let flags = read_u8_at(buf, 3)?;
+    let byte_order = if flags & FLAG_LITTLE_ENDIAN != 0 {
+        Endianness::LittleEndian
+    } else {
+        Endianness::BigEndian
+    };
+    let envelope_dim = match (flags >> ENVELOPE_SHIFT) & ENVELOPE_MASK {
+        0 => None,
+        1 => Some(WKBDimension::Xy),
+        2 => Some(WKBDimension::Xyz),
+        3 => Some(WKBDimension::Xym),
+        4 => Some(WKBDimension::Xyzm),
+        code => {
+            return Err(WKBError::InvalidHeader {
+                reason: format!("Invalid GeoPackage envelope contents indicator {}", code).into(),
+                offset: 3,
+            })
+        }
+    };
+
+    let srs_id = read_u32_at(buf, byte_order, 4)? as i32;
+
+    let envelope_len = envelope_size(envelope_dim);
+    check_bounds(buf, 8, envelope_len as u64)?;
+    let envelope = envelope_dim.map(|dim| {
+        let mut values = vec![0.; 2 * dim.size()];
+        let bytes = &buf[8..8 + envelope_len];
+        match byte_order {
+            Endianness::BigEndian => BigEndian::read_f64_into(bytes, &mut values),
+            Endianness::LittleEndian => LittleEndian::read_f64_into(bytes, &mut values),
+        }
+        GpkgEnvelope::from_values(dim, &values)
+    });
+
+    let header_size = 8 + envelope_len;
+    let geometry = Wkb::try_new_at(buf, header_size as u64, 0)?;
+    let wkb = &buf[header_size..header_size + geometry.size() as usize];
+
+    Ok(GpkgGeometry {
+        srs_id,
+        envelope,
+        is_empty: flags & FLAG_EMPTY != 0,
+        is_extended: flags & FLAG_EXTENDED != 0,
+        header_size,
+        wkb,
+        geometry,
+    })
+}
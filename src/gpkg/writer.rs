@@ -0,0 +1,100 @@
+use std::io::Write;
+
+use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+use geo_traits::GeometryTrait;
+
+use crate::bounds::Bounds;
+use crate::common::WKBDimension;
+use crate::error::{WKBError, WKBResult};
+use crate::gpkg::*;
+use crate::writer::{geometry_wkb_size, write_geometry, WriteOptions};
+use crate::Endianness;
+
+/// The number of bytes this geometry will take up when written as a GeoPackage binary blob with
+/// an envelope of the given dimension
+pub fn gpkg_geometry_size(
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    envelope: Option<WKBDimension>,
+) -> usize {
+    8 + envelope_size(envelope) + geometry_wkb_size(geom, WriteOptions::default())
+}
+
+/// Write a geometry to a Writer as a GeoPackage binary blob
+///
+/// The header has the given `srs_id` and, if `envelope` is not `None`, an envelope of that
+/// dimension computed from the coordinates of `geom`. The envelope of an empty geometry is all
+/// NaN. The header and the ISO WKB geometry that follows it are both written with `endianness`.
+///
+/// An envelope with z or m values can only be computed for a geometry that has them.
+pub fn write_gpkg_geometry(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    srs_id: i32,
+    envelope: Option<WKBDimension>,
+    endianness: Endianness,
+) -> WKBResult<()> {
+    let mut bounds = Bounds::new();
+    bounds.add_geometry(geom);
+
+    let mut flags = envelope_code(envelope) << ENVELOPE_SHIFT;
+    if matches!(endianness, Endianness::LittleEndian) {
+        flags |= FLAG_LITTLE_ENDIAN;
+    }
+    if bounds.is_empty() {
+        flags |= FLAG_EMPTY;
+    }
+
+    let envelope = envelope
+        .map(|dim| compute_envelope(&bounds, geom.dim().try_into()?, dim))
+        .transpose()?;
+
+    writer.write_all(&MAGIC)?;
+    writer.write_u8(VERSION_1)?;
+    writer.write_u8(flags)?;
+    match endianness {
+        Endianness::BigEndian => writer.write_i32::<BigEndian>(srs_id)?,
+        Endianness::LittleEndian => writer.write_i32::<LittleEndian>(srs_id)?,
+    }
+    if let Some(envelope) = envelope {
+        for value in envelope.values() {
+            match endianness {
+                Endianness::BigEndian => writer.write_f64::<BigEndian>(value)?,
+                Endianness::LittleEndian => writer.write_f64::<LittleEndian>(value)?,
+            }
+        }
+    }
+
+    let options = WriteOptions {
+        endianness,
+        ..Default::default()
+    };
+    write_geometry(writer, geom, options)
+}
+
+/// The envelope of dimension `dim` of a geometry of dimension `geometry_dim` with `bounds`
+fn compute_envelope(
+    bounds: &Bounds,
+    geometry_dim: WKBDimension,
+    dim: WKBDimension,
+) -> WKBResult<GpkgEnvelope> {
+    let (has_z, has_m) = (dim.has_z(), dim.has_m());
+    if (has_z && !geometry_dim.has_z()) || (has_m && !geometry_dim.has_m()) {
+        return Err(WKBError::IncorrectType(
+            format!(
+                "Cannot compute a {:?} envelope of a {:?} geometry",
+                dim, geometry_dim
+            )
+            .into(),
+        ));
+    }
+
+    let range = |i: usize| bounds.range(i).unwrap_or((f64::NAN, f64::NAN));
+    Ok(GpkgEnvelope {
+        min_x: range(0).0,
+        max_x: range(0).1,
+        min_y: range(1).0,
+        max_y: range(1).1,
+        z: has_z.then(|| range(2)),
+        m: has_m.then(|| range(3)),
+    })
+}
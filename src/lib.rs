@@ -1,10 +1,17 @@
 #![doc = include_str!("../README.md")]
 
+mod bounds;
 mod common;
 pub mod error;
+pub mod gpkg;
+pub mod mysql;
 pub mod reader;
+pub mod spatialite;
 #[cfg(test)]
 mod test;
+pub mod transcode;
+pub mod twkb;
+pub mod validate;
 pub mod writer;
 
-pub use common::{Endianness, WKBType};
+pub use common::{Endianness, WKBDimension, WKBFlavor, WKBType};
@@ -0,0 +1,188 @@
+//! Read and write geometries in the internal format of MySQL and MariaDB.
+//!
+//! MySQL stores and returns geometry values as a four-byte little-endian SRID followed by
+//! little-endian, two-dimensional WKB.
+//!
+//! MySQL 8 orders the axes of geographic SRIDs as their spatial reference system defines them,
+//! which is latitude first for EPSG:4326. Whether an SRID is geographic depends on the server's
+//! spatial reference system catalog, so the caller chooses whether to swap x and y.
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::common::WKBDimension;
+use crate::error::{WKBError, WKBResult};
+use crate::reader::util::read_u32_at;
+use crate::reader::{read_wkb, Polygon, Wkb};
+use crate::writer::{geometry_wkb_size, write_geometry, WriteOptions};
+use crate::{Endianness, WKBFlavor};
+
+/// A geometry read from the MySQL internal geometry format
+#[derive(Debug, Clone)]
+pub struct MysqlGeometry<'a> {
+    srid: u32,
+    wkb: Cow<'a, [u8]>,
+}
+
+impl MysqlGeometry<'_> {
+    /// The SRID of this geometry
+    pub fn srid(&self) -> u32 {
+        self.srid
+    }
+
+    /// The WKB bytes of the geometry, without the SRID
+    ///
+    /// If x and y were swapped when reading, these are the swapped bytes.
+    pub fn wkb(&self) -> &[u8] {
+        &self.wkb
+    }
+
+    /// The geometry
+    pub fn geometry(&self) -> Wkb<'_> {
+        read_wkb(&self.wkb).expect("MySQL geometry was validated when it was read")
+    }
+}
+
+/// Read a geometry in the MySQL internal geometry format.
+///
+/// If `swap_xy` is true, the x and y values of each coordinate are swapped, which copies the
+/// WKB. Otherwise the geometry borrows from `buf`. Byte offsets in errors are from the start of
+/// `buf`, including the SRID.
+///
+/// ```
+/// use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+/// use wkb::mysql::read_mysql_geometry;
+///
+/// // SELECT ST_GeomFromText('POINT(1 2)', 4326)
+/// let mut buf = vec![0xe6, 0x10, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00];
+/// buf.extend_from_slice(&1_f64.to_le_bytes());
+/// buf.extend_from_slice(&2_f64.to_le_bytes());
+///
+/// let geometry = read_mysql_geometry(&buf, true).unwrap();
+/// assert_eq!(geometry.srid(), 4326);
+/// let wkb = geometry.geometry();
+/// let GeometryType::Point(point) = wkb.as_type() else {
+///     unreachable!()
+/// };
+/// assert_eq!(point.coord().unwrap().x_y(), (2., 1.));
+/// ```
+pub fn read_mysql_geometry(buf: &[u8], swap_xy: bool) -> WKBResult<MysqlGeometry<'_>> {
+    let srid = read_u32_at(buf, Endianness::LittleEndian, 0)?;
+    let size = Wkb::try_new_at(buf, 4, 0)?.size() as usize;
+    let wkb = &buf[4..4 + size];
+
+    let wkb = if swap_xy {
+        let mut wkb = wkb.to_vec();
+        swap_coords(&mut wkb);
+        Cow::Owned(wkb)
+    } else {
+        Cow::Borrowed(wkb)
+    };
+    Ok(MysqlGeometry { srid, wkb })
+}
+
+/// The options to write the WKB of a MySQL geometry with
+///
+/// MySQL only reads OGC 1.1 WKB, so Triangles are written as Polygons and other types that are
+/// not in OGC 1.1 return an error.
+fn mysql_write_options() -> WriteOptions {
+    WriteOptions {
+        endianness: Endianness::LittleEndian,
+        flavor: WKBFlavor::Ogc11,
+        ..Default::default()
+    }
+}
+
+/// The number of bytes this geometry will take up in the MySQL internal geometry format
+pub fn mysql_geometry_size(geom: &impl GeometryTrait<T: Into<f64>>) -> usize {
+    4 + geometry_wkb_size(geom, mysql_write_options())
+}
+
+/// Write a geometry to a Writer in the MySQL internal geometry format
+///
+/// If `swap_xy` is true, the x and y values of each coordinate are swapped. MySQL only supports
+/// two-dimensional geometries, so a geometry with z or m values returns an error. Triangles are
+/// written as Polygons, and geometry types that MySQL cannot read return an error.
+pub fn write_mysql_geometry(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    srid: u32,
+    swap_xy: bool,
+) -> WKBResult<()> {
+    let dim: WKBDimension = geom.dim().try_into()?;
+    if dim != WKBDimension::Xy {
+        return Err(WKBError::IncorrectType(
+            format!("MySQL geometries must be two-dimensional, not {:?}", dim).into(),
+        ));
+    }
+
+    let options = mysql_write_options();
+    writer.write_u32::<LittleEndian>(srid)?;
+    if swap_xy {
+        let mut wkb = Vec::with_capacity(geometry_wkb_size(geom, options));
+        write_geometry(&mut wkb, geom, options)?;
+        swap_coords(&mut wkb);
+        writer.write_all(&wkb)?;
+        Ok(())
+    } else {
+        write_geometry(writer, geom, options)
+    }
+}
+
+/// Swap the x and y values of each coordinate of the valid WKB geometry in `wkb`
+fn swap_coords(wkb: &mut [u8]) {
+    let mut offsets = Vec::new();
+    let geom = read_wkb(wkb).expect("WKB was validated before swapping coordinates");
+    coord_offsets(&geom, &mut offsets);
+
+    // Swapping the bytes of x and y does not depend on their byte order
+    for offset in offsets {
+        let offset = offset as usize;
+        let (x, y) = wkb[offset..offset + 16].split_at_mut(8);
+        x.swap_with_slice(y);
+    }
+}
+
+/// Append the offset of each coordinate of `geom` to `offsets`
+fn coord_offsets(geom: &Wkb, offsets: &mut Vec<u64>) {
+    use GeometryType::*;
+    match geom.as_type() {
+        Point(p) => offsets.extend(p.coord().map(|c| c.offset())),
+        LineString(ls) => offsets.extend(ls.coords().map(|c| c.offset())),
+        Polygon(p) => polygon_coord_offsets(p, offsets),
+        MultiPoint(mp) => {
+            for point in mp.points() {
+                offsets.extend(point.coord().map(|c| c.offset()));
+            }
+        }
+        MultiLineString(ml) => {
+            for line_string in ml.line_strings() {
+                offsets.extend(line_string.coords().map(|c| c.offset()));
+            }
+        }
+        MultiPolygon(mp) => {
+            for polygon in mp.polygons() {
+                polygon_coord_offsets(&polygon, offsets);
+            }
+        }
+        GeometryCollection(gc) => {
+            for geometry in gc.geometries() {
+                coord_offsets(geometry, offsets);
+            }
+        }
+        Triangle(tri) => polygon_coord_offsets(tri.as_polygon(), offsets),
+        Rect(_) | Line(_) => unreachable!("WKB has no Rect or Line geometries"),
+    }
+}
+
+fn polygon_coord_offsets(polygon: &Polygon, offsets: &mut Vec<u64>) {
+    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+        offsets.extend(ring.coords().map(|c| c.offset()));
+    }
+}
@@ -0,0 +1,168 @@
+use geo_traits::{CoordTrait, Dimensions, MultiPointTrait, PointTrait, RectTrait};
+
+use crate::bounds::{value_indices, Bounds};
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::flat_coords::CoordRun;
+use crate::reader::geometry::Wkb;
+use crate::reader::lazy::LazyWkb;
+
+/// The bounding box of a WKB geometry, read by [`read_wkb_bounds`][crate::reader::read_wkb_bounds]
+///
+/// This implements [`RectTrait`], with corners that have the dimension of the geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WKBBounds {
+    /// The minimum of each value, in the order of the geometry's coordinates
+    min: [f64; 4],
+    /// The maximum of each value, in the order of the geometry's coordinates
+    max: [f64; 4],
+    dim: WKBDimension,
+}
+
+impl WKBBounds {
+    /// Scan the geometry at the start of `buf`, returning `None` if it has no coordinates
+    pub(crate) fn try_new(buf: &[u8]) -> WKBResult<Option<Self>> {
+        let geometry = LazyWkb::try_new_at(buf, 0, 0)?;
+        let mut bounds = Bounds::new();
+        add_geometry(&mut bounds, &geometry)?;
+        if bounds.is_empty() {
+            return Ok(None);
+        }
+
+        let dim = geometry.dimension();
+        let mut min = [f64::NAN; 4];
+        let mut max = [f64::NAN; 4];
+        for (n, i) in value_indices(dim.into()).iter().enumerate() {
+            if let Some((lo, hi)) = bounds.range(*i) {
+                min[n] = lo;
+                max[n] = hi;
+            }
+        }
+        Ok(Some(Self { min, max, dim }))
+    }
+
+    /// The dimension of the geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The minimum and maximum x values
+    pub fn x_range(&self) -> (f64, f64) {
+        (self.min[0], self.max[0])
+    }
+
+    /// The minimum and maximum y values
+    pub fn y_range(&self) -> (f64, f64) {
+        (self.min[1], self.max[1])
+    }
+
+    /// The minimum and maximum z values, if the geometry has z values
+    pub fn z_range(&self) -> Option<(f64, f64)> {
+        self.dim.has_z().then_some((self.min[2], self.max[2]))
+    }
+
+    /// The minimum and maximum m values, if the geometry has m values
+    pub fn m_range(&self) -> Option<(f64, f64)> {
+        let n = self.dim.size() - 1;
+        self.dim.has_m().then_some((self.min[n], self.max[n]))
+    }
+}
+
+impl RectTrait for WKBBounds {
+    type T = f64;
+    type CoordType<'a> = BoundsCoord;
+
+    fn dim(&self) -> Dimensions {
+        self.dim.into()
+    }
+
+    fn min(&self) -> Self::CoordType<'_> {
+        BoundsCoord {
+            values: self.min,
+            dim: self.dim,
+        }
+    }
+
+    fn max(&self) -> Self::CoordType<'_> {
+        BoundsCoord {
+            values: self.max,
+            dim: self.dim,
+        }
+    }
+}
+
+/// A corner of a [`WKBBounds`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundsCoord {
+    values: [f64; 4],
+    dim: WKBDimension,
+}
+
+impl CoordTrait for BoundsCoord {
+    type T = f64;
+
+    fn dim(&self) -> Dimensions {
+        self.dim.into()
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        assert!(n < self.dim.size());
+        self.values[n]
+    }
+
+    fn x(&self) -> Self::T {
+        self.values[0]
+    }
+
+    fn y(&self) -> Self::T {
+        self.values[1]
+    }
+}
+
+fn add_coord_run(bounds: &mut Bounds, run: CoordRun, dim: WKBDimension) {
+    let indices = value_indices(dim.into());
+    for (value, i) in run.values().zip(indices.iter().cycle()) {
+        bounds.add_value(*i, value);
+    }
+}
+
+/// Add the coordinates of `geometry` to `bounds`, reading the buffer without allocating
+fn add_geometry(bounds: &mut Bounds, geometry: &LazyWkb) -> WKBResult<()> {
+    let dim = geometry.dimension();
+    match geometry.wkb_type() {
+        WKBType::Point(_) | WKBType::MultiPoint(_) => match geometry.parse()? {
+            Wkb::Point(point) => {
+                if let Some(coord) = point.coord() {
+                    bounds.add_coord(&coord);
+                }
+            }
+            Wkb::MultiPoint(multi_point) => {
+                for point in multi_point.points() {
+                    if let Some(coord) = point.coord() {
+                        bounds.add_coord(&coord);
+                    }
+                }
+            }
+            _ => unreachable!(),
+        },
+        WKBType::LineString(_) => {
+            let Wkb::LineString(line_string) = geometry.parse()? else {
+                unreachable!()
+            };
+            add_coord_run(bounds, line_string.coord_run(), dim);
+        }
+        WKBType::Polygon(_) | WKBType::Triangle(_) => {
+            // Interior rings are included too, since they are only known to lie within the
+            // exterior ring if the geometry is valid
+            for ring in geometry.rings()? {
+                add_coord_run(bounds, ring?.coord_run(), dim);
+            }
+        }
+        _ => {
+            for (i, part) in geometry.parts()?.enumerate() {
+                add_geometry(bounds, &part?).map_err(|err| err.in_part(i))?;
+            }
+        }
+    }
+    Ok(())
+}
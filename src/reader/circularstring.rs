@@ -0,0 +1,89 @@
+use crate::common::WKBDimension;
+use crate::error::WKBResult;
+use crate::reader::coord::Coord;
+use crate::reader::linestring::LineString;
+use crate::Endianness;
+use geo_traits::Dimensions;
+use geo_traits::LineStringTrait;
+
+/// A WKB CircularString
+///
+/// A CircularString is a sequence of circular arcs, each defined by three control points: a start
+/// point, any point along the arc and an end point. The end point of each arc is the start point
+/// of the next, so a CircularString with `n` arcs has `2n + 1` control points.
+///
+/// The [`LineStringTrait`] implementation exposes the control points, **not** the curve itself.
+/// Use [`CurvedWkb::linearize`][crate::reader::CurvedWkb::linearize] to approximate the curve as
+/// a LineString.
+///
+/// This has the same layout as a LineString, so access to any control point is `O(1)`.
+#[derive(Debug, Clone, Copy)]
+pub struct CircularString<'a>(LineString<'a>);
+
+impl<'a> CircularString<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        LineString::try_new(buf, byte_order, offset, dim).map(Self)
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        self.0.size()
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.0.dimension()
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.0.srid()
+    }
+}
+
+impl<'a> LineStringTrait for CircularString<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.0.dim()
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.coord_unchecked(i)
+    }
+}
+
+impl<'a> LineStringTrait for &'a CircularString<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.0.dim()
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.coord_unchecked(i)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::curve::{read_parts, Curve};
+use crate::Endianness;
+
+/// A WKB CompoundCurve
+///
+/// A CompoundCurve is a sequence of LineString and CircularString segments, where each segment
+/// starts at the end point of the previous one.
+#[derive(Debug, Clone)]
+pub struct CompoundCurve<'a> {
+    /// The LineString and CircularString segments of this curve
+    segments: Vec<Curve<'a>>,
+    dim: WKBDimension,
+    srid: Option<i32>,
+}
+
+impl<'a> CompoundCurve<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let (segments, srid) = read_parts(
+            buf,
+            byte_order,
+            offset,
+            WKBType::CompoundCurve(dim),
+            Curve::try_new_at,
+            Curve::size,
+        )?;
+        Ok(Self {
+            segments,
+            dim,
+            srid,
+        })
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numCurves
+        let mut header = 1 + 4 + 4;
+        if self.srid.is_some() {
+            header += 4;
+        }
+        self.segments.iter().fold(header, |acc, x| acc + x.size())
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The segments of this curve, each of which is a LineString or a CircularString
+    pub fn segments(&self) -> &[Curve<'a>] {
+        &self.segments
+    }
+}
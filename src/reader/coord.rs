@@ -1,7 +1,6 @@
-use std::io::Cursor;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
 
 use crate::common::WKBDimension;
-use crate::reader::util::ReadBytesExt;
 use crate::Endianness;
 use geo_traits::{CoordTrait, Dimensions};
 
@@ -49,26 +48,38 @@ impl<'a> Coord<'a> {
         }
     }
 
+    /// The offset into the buffer where this coordinate is located
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Read the f64 at the given byte offset.
+    ///
+    /// The enclosing geometry validated that this coordinate lies within the buffer when it was
+    /// constructed, so this read cannot fail.
+    #[inline]
+    fn read_f64_at(&self, offset: u64) -> f64 {
+        let bytes = &self.buf[offset as usize..];
+        match self.byte_order {
+            Endianness::BigEndian => BigEndian::read_f64(bytes),
+            Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+        }
+    }
+
     #[inline]
     fn get_x(&self) -> f64 {
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(self.offset);
-        reader.read_f64(self.byte_order).unwrap()
+        self.read_f64_at(self.offset)
     }
 
     #[inline]
     fn get_y(&self) -> f64 {
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(self.offset + F64_WIDTH);
-        reader.read_f64(self.byte_order).unwrap()
+        self.read_f64_at(self.offset + F64_WIDTH)
     }
 
     #[inline]
     fn get_nth_unchecked(&self, n: usize) -> f64 {
         debug_assert!(n < self.dim.size());
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(self.offset + (n as u64 * F64_WIDTH));
-        reader.read_f64(self.byte_order).unwrap()
+        self.read_f64_at(self.offset + (n as u64 * F64_WIDTH))
     }
 
     /// The number of bytes in this object
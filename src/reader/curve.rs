@@ -0,0 +1,310 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::linearize::{linearize_curved_wkb, validate_tolerance};
+use crate::reader::util::{
+    check_bounds, check_child_dimension, check_depth, read_header, read_srid, read_u32_at,
+};
+use crate::reader::{
+    CircularString, CompoundCurve, CurvePolygon, CurvedGeometryCollection, LineString, MultiCurve,
+    MultiSurface, Polygon, Wkb,
+};
+use crate::Endianness;
+
+/// skip endianness and wkb type
+const HEADER_BYTES: u64 = 5;
+
+/// A WKB geometry of any type, including the SQL/MM curve types.
+///
+/// Linear geometries are parsed as a [`Wkb`], which implements
+/// [`GeometryTrait`][geo_traits::GeometryTrait]. Curve geometries have no equivalent in
+/// [`geo_traits`], so they are exposed through their own types. Use [`CurvedWkb::linearize`] to
+/// approximate a curve geometry with a linear one.
+///
+/// A GeometryCollection is parsed as a linear geometry unless it contains a curve geometry, at
+/// any level of nesting.
+#[derive(Debug, Clone)]
+pub enum CurvedWkb<'a> {
+    /// A linear geometry
+    Linear(Wkb<'a>),
+    /// A WKB CircularString
+    CircularString(CircularString<'a>),
+    /// A WKB CompoundCurve
+    CompoundCurve(CompoundCurve<'a>),
+    /// A WKB CurvePolygon
+    CurvePolygon(CurvePolygon<'a>),
+    /// A WKB MultiCurve
+    MultiCurve(MultiCurve<'a>),
+    /// A WKB MultiSurface
+    MultiSurface(MultiSurface<'a>),
+    /// A WKB GeometryCollection that contains at least one curve geometry
+    GeometryCollection(CurvedGeometryCollection<'a>),
+}
+
+impl<'a> CurvedWkb<'a> {
+    /// Parse the WKB geometry at the start of `buf`.
+    ///
+    /// This is equivalent to [`read_curved_wkb`][crate::reader::read_curved_wkb].
+    pub fn try_new(buf: &'a [u8]) -> WKBResult<Self> {
+        Self::try_new_at(buf, 0, 0)
+    }
+
+    /// Parse the geometry starting at `offset` within `buf`, nested `depth` levels deep
+    pub(crate) fn try_new_at(buf: &'a [u8], offset: u64, depth: usize) -> WKBResult<Self> {
+        check_depth(depth, offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+
+        let out = match wkb_type {
+            WKBType::CircularString(dim) => {
+                Self::CircularString(CircularString::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::CompoundCurve(dim) => {
+                Self::CompoundCurve(CompoundCurve::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::CurvePolygon(dim) => {
+                Self::CurvePolygon(CurvePolygon::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::MultiCurve(dim) => {
+                Self::MultiCurve(MultiCurve::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::MultiSurface(dim) => {
+                Self::MultiSurface(MultiSurface::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::GeometryCollection(dim) => {
+                let collection =
+                    CurvedGeometryCollection::try_new(buf, byte_order, offset, dim, depth)?;
+                match collection.into_linear() {
+                    Ok(collection) => Self::Linear(Wkb::GeometryCollection(collection)),
+                    Err(collection) => Self::GeometryCollection(collection),
+                }
+            }
+            _ => Self::Linear(Wkb::try_new_at(buf, offset, depth)?),
+        };
+        Ok(out)
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        use CurvedWkb::*;
+        match self {
+            Linear(g) => g.dimension(),
+            CircularString(g) => g.dimension(),
+            CompoundCurve(g) => g.dimension(),
+            CurvePolygon(g) => g.dimension(),
+            MultiCurve(g) => g.dimension(),
+            MultiSurface(g) => g.dimension(),
+            GeometryCollection(g) => g.dimension(),
+        }
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        use CurvedWkb::*;
+        match self {
+            Linear(g) => g.srid(),
+            CircularString(g) => g.srid(),
+            CompoundCurve(g) => g.srid(),
+            CurvePolygon(g) => g.srid(),
+            MultiCurve(g) => g.srid(),
+            MultiSurface(g) => g.srid(),
+            GeometryCollection(g) => g.srid(),
+        }
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        use CurvedWkb::*;
+        match self {
+            Linear(g) => g.size(),
+            CircularString(g) => g.size(),
+            CompoundCurve(g) => g.size(),
+            CurvePolygon(g) => g.size(),
+            MultiCurve(g) => g.size(),
+            MultiSurface(g) => g.size(),
+            GeometryCollection(g) => g.size(),
+        }
+    }
+
+    /// Approximate this geometry with a linear geometry, returned as little-endian ISO WKB.
+    ///
+    /// Each circular arc is replaced by a sequence of straight segments that deviate from the arc
+    /// by at most `tolerance`. Z and M values are interpolated along each arc. The result can be
+    /// parsed with [`read_wkb`][crate::reader::read_wkb] to access it through [`geo_traits`]:
+    ///
+    /// | Curve type     | Linear type     |
+    /// |----------------|-----------------|
+    /// | CircularString | LineString      |
+    /// | CompoundCurve  | LineString      |
+    /// | CurvePolygon   | Polygon         |
+    /// | MultiCurve     | MultiLineString |
+    /// | MultiSurface   | MultiPolygon    |
+    ///
+    /// Each geometry in a GeometryCollection is approximated in the same way. Linear geometries
+    /// are written unchanged. Any SRID is not included in the output.
+    ///
+    /// Returns an error if `tolerance` is not a positive, finite number, or if the arcs of the
+    /// geometry would need an unreasonable number of segments in total to stay within
+    /// `tolerance`.
+    pub fn linearize(&self, tolerance: f64) -> WKBResult<Vec<u8>> {
+        validate_tolerance(tolerance)?;
+        linearize_curved_wkb(self, tolerance)
+    }
+}
+
+/// A curve nested within a CompoundCurve, CurvePolygon or MultiCurve
+#[derive(Debug, Clone)]
+pub enum Curve<'a> {
+    /// A WKB LineString
+    LineString(LineString<'a>),
+    /// A WKB CircularString
+    CircularString(CircularString<'a>),
+    /// A WKB CompoundCurve
+    ///
+    /// This never occurs as a segment of a CompoundCurve.
+    CompoundCurve(CompoundCurve<'a>),
+}
+
+impl<'a> Curve<'a> {
+    /// Parse the curve starting at `offset` and check that it is allowed within `parent`
+    pub(crate) fn try_new_at(buf: &'a [u8], offset: u64, parent: &WKBType) -> WKBResult<Self> {
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        check_child_dimension(parent, &wkb_type, offset)?;
+
+        let nested_compound = matches!(parent, WKBType::CompoundCurve(_));
+        let out = match wkb_type {
+            WKBType::LineString(dim) => {
+                Self::LineString(LineString::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::CircularString(dim) => {
+                Self::CircularString(CircularString::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::CompoundCurve(dim) if !nested_compound => {
+                Self::CompoundCurve(CompoundCurve::try_new(buf, byte_order, offset, dim)?)
+            }
+            found => {
+                // Report the most general type that the parent allows
+                let dim = parent.dimension();
+                let expected = if nested_compound {
+                    WKBType::CircularString(dim)
+                } else {
+                    WKBType::CompoundCurve(dim)
+                };
+                return Err(WKBError::UnexpectedGeometryType {
+                    expected,
+                    found,
+                    offset,
+                    path: vec![],
+                });
+            }
+        };
+        Ok(out)
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        match self {
+            Self::LineString(g) => g.size(),
+            Self::CircularString(g) => g.size(),
+            Self::CompoundCurve(g) => g.size(),
+        }
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        match self {
+            Self::LineString(g) => g.dimension(),
+            Self::CircularString(g) => g.dimension(),
+            Self::CompoundCurve(g) => g.dimension(),
+        }
+    }
+}
+
+/// A surface nested within a MultiSurface
+#[derive(Debug, Clone)]
+pub enum Surface<'a> {
+    /// A WKB Polygon
+    Polygon(Polygon<'a>),
+    /// A WKB CurvePolygon
+    CurvePolygon(CurvePolygon<'a>),
+}
+
+impl<'a> Surface<'a> {
+    /// Parse the surface starting at `offset` and check that it is allowed within `parent`
+    pub(crate) fn try_new_at(buf: &'a [u8], offset: u64, parent: &WKBType) -> WKBResult<Self> {
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        check_child_dimension(parent, &wkb_type, offset)?;
+
+        let out = match wkb_type {
+            WKBType::Polygon(dim) => Self::Polygon(Polygon::try_new(buf, byte_order, offset, dim)?),
+            WKBType::CurvePolygon(dim) => {
+                Self::CurvePolygon(CurvePolygon::try_new(buf, byte_order, offset, dim)?)
+            }
+            found => {
+                return Err(WKBError::UnexpectedGeometryType {
+                    expected: WKBType::CurvePolygon(parent.dimension()),
+                    found,
+                    offset,
+                    path: vec![],
+                })
+            }
+        };
+        Ok(out)
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        match self {
+            Self::Polygon(g) => g.size(),
+            Self::CurvePolygon(g) => g.size(),
+        }
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        match self {
+            Self::Polygon(g) => g.dimension(),
+            Self::CurvePolygon(g) => g.dimension(),
+        }
+    }
+}
+
+/// Read the SRID and each nested part of the geometry of type `parent` starting at `offset`
+pub(crate) fn read_parts<'a, T>(
+    buf: &'a [u8],
+    byte_order: Endianness,
+    mut offset: u64,
+    parent: WKBType,
+    read_part: impl Fn(&'a [u8], u64, &WKBType) -> WKBResult<T>,
+    part_size: impl Fn(&T) -> u64,
+) -> WKBResult<(Vec<T>, Option<i32>)> {
+    let srid = read_srid(buf, byte_order, offset)?;
+    if srid.is_some() {
+        offset += 4;
+    }
+
+    let num_parts = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
+
+    // - existing offset into buffer
+    // - 1: byteOrder
+    // - 4: wkbType
+    // - 4: numParts
+    let mut part_offset = offset + 1 + 4 + 4;
+
+    // Each part takes at least 9 bytes for its header, so check the count against the buffer
+    // before allocating.
+    check_bounds(buf, part_offset, 9 * num_parts as u64)?;
+
+    let mut parts = Vec::with_capacity(num_parts);
+    for i in 0..num_parts {
+        let part = read_part(buf, part_offset, &parent).map_err(|err| err.in_part(i))?;
+        part_offset += part_size(&part);
+        parts.push(part);
+    }
+
+    Ok((parts, srid))
+}
@@ -0,0 +1,96 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::curve::{read_parts, CurvedWkb};
+use crate::reader::util::{check_child_dimension, read_header};
+use crate::reader::GeometryCollection;
+use crate::Endianness;
+
+/// A WKB GeometryCollection that contains at least one curve geometry
+///
+/// A GeometryCollection of only linear geometries is read as a linear
+/// [`GeometryCollection`][crate::reader::GeometryCollection] instead.
+#[derive(Debug, Clone)]
+pub struct CurvedGeometryCollection<'a> {
+    /// The geometries in this collection
+    geometries: Vec<CurvedWkb<'a>>,
+    dim: WKBDimension,
+    srid: Option<i32>,
+}
+
+impl<'a> CurvedGeometryCollection<'a> {
+    /// Parse the collection starting at `offset`, nested `depth` levels deep
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+        depth: usize,
+    ) -> WKBResult<Self> {
+        let (geometries, srid) = read_parts(
+            buf,
+            byte_order,
+            offset,
+            WKBType::GeometryCollection(dim),
+            |buf, offset, parent| {
+                let (_, wkb_type) = read_header(buf, offset)?;
+                check_child_dimension(parent, &wkb_type, offset)?;
+                CurvedWkb::try_new_at(buf, offset, depth + 1)
+            },
+            CurvedWkb::size,
+        )?;
+        Ok(Self {
+            geometries,
+            dim,
+            srid,
+        })
+    }
+
+    /// Convert to a linear collection if none of the geometries are curves
+    pub(crate) fn into_linear(self) -> Result<GeometryCollection<'a>, Self> {
+        if !self
+            .geometries
+            .iter()
+            .all(|geometry| matches!(geometry, CurvedWkb::Linear(_)))
+        {
+            return Err(self);
+        }
+        let geometries = self
+            .geometries
+            .into_iter()
+            .map(|geometry| match geometry {
+                CurvedWkb::Linear(geometry) => geometry,
+                _ => unreachable!(),
+            })
+            .collect();
+        Ok(GeometryCollection::new(geometries, self.dim, self.srid))
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numGeometries
+        let mut header = 1 + 4 + 4;
+        if self.srid.is_some() {
+            header += 4;
+        }
+        self.geometries.iter().fold(header, |acc, x| acc + x.size())
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The geometries in this collection
+    pub fn geometries(&self) -> &[CurvedWkb<'a>] {
+        &self.geometries
+    }
+}
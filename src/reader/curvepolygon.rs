@@ -0,0 +1,66 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::curve::{read_parts, Curve};
+use crate::Endianness;
+
+/// A WKB CurvePolygon
+///
+/// A CurvePolygon is a polygon whose rings may be any closed curve: a LineString, a
+/// CircularString or a CompoundCurve.
+#[derive(Debug, Clone)]
+pub struct CurvePolygon<'a> {
+    /// The rings of this polygon, starting with the exterior ring
+    rings: Vec<Curve<'a>>,
+    dim: WKBDimension,
+    srid: Option<i32>,
+}
+
+impl<'a> CurvePolygon<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let (rings, srid) = read_parts(
+            buf,
+            byte_order,
+            offset,
+            WKBType::CurvePolygon(dim),
+            Curve::try_new_at,
+            Curve::size,
+        )?;
+        Ok(Self { rings, dim, srid })
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numRings
+        let mut header = 1 + 4 + 4;
+        if self.srid.is_some() {
+            header += 4;
+        }
+        self.rings.iter().fold(header, |acc, x| acc + x.size())
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The rings of this polygon.
+    ///
+    /// The first ring, if any, is the exterior ring and the rest are interior rings.
+    pub fn rings(&self) -> &[Curve<'a>] {
+        &self.rings
+    }
+}
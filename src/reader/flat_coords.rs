@@ -0,0 +1,201 @@
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::WKBDimension;
+use crate::Endianness;
+
+/// Coordinates stored back-to-back in a WKB buffer with a single byte order
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CoordRun<'a> {
+    /// The bytes of the coordinates
+    bytes: &'a [u8],
+    byte_order: Endianness,
+}
+
+impl<'a> CoordRun<'a> {
+    /// Construct a run of `num_coords` coordinates starting at `offset`.
+    ///
+    /// Callers must have already checked that the coordinates lie within `buf`.
+    pub(crate) fn new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        num_coords: usize,
+        dim: WKBDimension,
+    ) -> Self {
+        let start = offset as usize;
+        let end = start + num_coords * dim.size() * 8;
+        Self {
+            bytes: &buf[start..end],
+            byte_order,
+        }
+    }
+
+    /// Decode each value of the coordinates in turn
+    pub(crate) fn values(&self) -> impl Iterator<Item = f64> + 'a {
+        let byte_order = self.byte_order;
+        self.bytes
+            .chunks_exact(8)
+            .map(move |bytes| match byte_order {
+                Endianness::BigEndian => BigEndian::read_f64(bytes),
+                Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+            })
+    }
+}
+
+/// All the coordinates of a WKB geometry, for decoding them at once.
+///
+/// Decoding in bulk avoids the cost of reading each value through
+/// [`CoordTrait`][geo_traits::CoordTrait]. When the WKB has the byte order of the host, decoding
+/// interleaved values is a copy of the underlying bytes.
+///
+/// ```
+/// use geo_traits::GeometryTrait;
+/// use wkb::reader::{read_wkb, Wkb};
+///
+/// let line_string = geo_types::LineString::from(vec![(1., 2.), (3., 4.)]);
+/// let mut buf = Vec::new();
+/// wkb::writer::write_line_string(&mut buf, &line_string, Default::default()).unwrap();
+///
+/// let Wkb::LineString(line_string) = read_wkb(&buf).unwrap() else {
+///     unreachable!()
+/// };
+/// let coords = line_string.flat_coords();
+///
+/// let mut interleaved = Vec::new();
+/// coords.extend_interleaved(&mut interleaved);
+/// assert_eq!(interleaved, [1., 2., 3., 4.]);
+///
+/// let mut columns = vec![Vec::new(), Vec::new()];
+/// coords.extend_columns(&mut columns);
+/// assert_eq!(columns, [[1., 3.], [2., 4.]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlatCoords<'a> {
+    runs: Vec<CoordRun<'a>>,
+    dim: WKBDimension,
+    num_coords: usize,
+}
+
+impl<'a> FlatCoords<'a> {
+    pub(crate) fn new(runs: Vec<CoordRun<'a>>, dim: WKBDimension) -> Self {
+        let num_bytes = runs.iter().map(|run| run.bytes.len()).sum::<usize>();
+        Self {
+            runs,
+            dim,
+            num_coords: num_bytes / (dim.size() * 8),
+        }
+    }
+
+    /// The dimension of the coordinates
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The number of coordinates
+    ///
+    /// The coordinates of empty Points in a MultiPoint are included, with NaN values.
+    pub fn num_coords(&self) -> usize {
+        self.num_coords
+    }
+
+    /// Decode the coordinates into `out` as interleaved values, with the x, y and any z and m
+    /// values of each coordinate in turn.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `out` is not the number of coordinates times the number of values in
+    /// each coordinate.
+    pub fn read_interleaved(&self, out: &mut [f64]) {
+        assert_eq!(
+            out.len(),
+            self.num_coords * self.dim.size(),
+            "output length does not match the number of values"
+        );
+        let mut start = 0;
+        for run in &self.runs {
+            let end = start + run.bytes.len() / 8;
+            match run.byte_order {
+                Endianness::BigEndian => BigEndian::read_f64_into(run.bytes, &mut out[start..end]),
+                Endianness::LittleEndian => {
+                    LittleEndian::read_f64_into(run.bytes, &mut out[start..end])
+                }
+            }
+            start = end;
+        }
+    }
+
+    /// Decode the coordinates as interleaved values appended to `out`
+    ///
+    /// See [`FlatCoords::read_interleaved`].
+    pub fn extend_interleaved(&self, out: &mut Vec<f64>) {
+        let start = out.len();
+        out.resize(start + self.num_coords * self.dim.size(), 0.);
+        self.read_interleaved(&mut out[start..]);
+    }
+
+    /// Decode the coordinates into a separate column for each dimension, in the order x, y and
+    /// any z and m.
+    ///
+    /// # Panics
+    ///
+    /// If `columns` does not have one column for each dimension, or the length of a column is
+    /// not the number of coordinates.
+    pub fn read_columns(&self, columns: &mut [&mut [f64]]) {
+        assert_eq!(
+            columns.len(),
+            self.dim.size(),
+            "number of columns does not match the dimension"
+        );
+        assert!(
+            columns.iter().all(|column| column.len() == self.num_coords),
+            "column length does not match the number of coordinates"
+        );
+        let mut start = 0;
+        for run in &self.runs {
+            start += match run.byte_order {
+                Endianness::BigEndian => read_run_columns::<BigEndian>(run.bytes, columns, start),
+                Endianness::LittleEndian => {
+                    read_run_columns::<LittleEndian>(run.bytes, columns, start)
+                }
+            };
+        }
+    }
+
+    /// Decode the coordinates into a separate column for each dimension, appending to the
+    /// columns in `columns`
+    ///
+    /// See [`FlatCoords::read_columns`].
+    ///
+    /// # Panics
+    ///
+    /// If `columns` does not have one column for each dimension, in which case no column is
+    /// changed.
+    pub fn extend_columns(&self, columns: &mut [Vec<f64>]) {
+        assert_eq!(
+            columns.len(),
+            self.dim.size(),
+            "number of columns does not match the dimension"
+        );
+        let mut columns = columns
+            .iter_mut()
+            .map(|column| {
+                let start = column.len();
+                column.resize(start + self.num_coords, 0.);
+                &mut column[start..]
+            })
+            .collect::<Vec<_>>();
+        self.read_columns(&mut columns);
+    }
+}
+
+/// Decode the coordinates in `bytes` into `columns` from index `start`, returning the number of
+/// coordinates decoded
+fn read_run_columns<B: ByteOrder>(bytes: &[u8], columns: &mut [&mut [f64]], start: usize) -> usize {
+    let coord_size = columns.len() * 8;
+    for (i, coord) in bytes.chunks_exact(coord_size).enumerate() {
+        for (column, value) in columns.iter_mut().zip(coord.chunks_exact(8)) {
+            column[start + i] = B::read_f64(value);
+        }
+    }
+    bytes.len() / coord_size
+}
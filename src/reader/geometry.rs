@@ -1,52 +1,93 @@
-use std::io::Cursor;
-
-use byteorder::ReadBytesExt;
-
 use crate::common::{WKBDimension, WKBType};
-use crate::error::WKBResult;
+use crate::error::{WKBError, WKBResult};
+use crate::reader::util::{check_depth, read_header};
 use crate::reader::{
     GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    PolyhedralSurface, Tin, Triangle,
 };
-use crate::Endianness;
-use geo_traits::{
-    Dimensions, GeometryTrait, UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
-};
+use geo_traits::{Dimensions, GeometryTrait, UnimplementedLine, UnimplementedRect};
 
+/// A parsed WKB geometry of any type.
+///
+/// This borrows from the underlying buffer and implements [`GeometryTrait`]. Match on the variants
+/// to access the concrete geometry types and their inherent methods.
+///
+/// Through [`GeometryTrait`], a PolyhedralSurface or TIN is exposed as a MultiPolygon of its
+/// patches, and an empty Triangle is exposed as an empty Polygon.
 #[derive(Debug, Clone)]
 pub enum Wkb<'a> {
+    /// A WKB Point
     Point(Point<'a>),
+    /// A WKB LineString
     LineString(LineString<'a>),
+    /// A WKB Polygon
     Polygon(Polygon<'a>),
+    /// A WKB MultiPoint
     MultiPoint(MultiPoint<'a>),
+    /// A WKB MultiLineString
     MultiLineString(MultiLineString<'a>),
+    /// A WKB MultiPolygon
     MultiPolygon(MultiPolygon<'a>),
+    /// A WKB GeometryCollection
     GeometryCollection(GeometryCollection<'a>),
+    /// A WKB PolyhedralSurface
+    PolyhedralSurface(PolyhedralSurface<'a>),
+    /// A WKB TIN
+    Tin(Tin<'a>),
+    /// A WKB Triangle
+    Triangle(Triangle<'a>),
 }
 
 impl<'a> Wkb<'a> {
+    /// Parse the WKB geometry at the start of `buf`.
+    ///
+    /// This is equivalent to [`read_wkb`][crate::reader::read_wkb].
     pub fn try_new(buf: &'a [u8]) -> WKBResult<Self> {
-        let mut reader = Cursor::new(buf);
-        let byte_order = Endianness::try_from(reader.read_u8()?).unwrap();
-        let wkb_type = WKBType::from_buffer(buf)?;
+        Self::try_new_at(buf, 0, 0)
+    }
+
+    /// Parse the geometry starting at `offset` within `buf`, nested `depth` levels deep
+    pub(crate) fn try_new_at(buf: &'a [u8], offset: u64, depth: usize) -> WKBResult<Self> {
+        check_depth(depth, offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
 
         let out = match wkb_type {
-            WKBType::Point(dim) => Wkb::Point(Point::new(buf, byte_order, 0, dim)),
-            WKBType::LineString(dim) => Wkb::LineString(LineString::new(buf, byte_order, 0, dim)),
-            WKBType::Polygon(dim) => Wkb::Polygon(Polygon::new(buf, byte_order, 0, dim)),
-            WKBType::MultiPoint(dim) => Wkb::MultiPoint(MultiPoint::new(buf, byte_order, dim)),
+            WKBType::Point(dim) => Wkb::Point(Point::try_new(buf, byte_order, offset, dim)?),
+            WKBType::LineString(dim) => {
+                Wkb::LineString(LineString::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::Polygon(dim) => Wkb::Polygon(Polygon::try_new(buf, byte_order, offset, dim)?),
+            WKBType::MultiPoint(dim) => {
+                Wkb::MultiPoint(MultiPoint::try_new(buf, byte_order, offset, dim)?)
+            }
             WKBType::MultiLineString(dim) => {
-                Wkb::MultiLineString(MultiLineString::new(buf, byte_order, dim))
+                Wkb::MultiLineString(MultiLineString::try_new(buf, byte_order, offset, dim)?)
             }
             WKBType::MultiPolygon(dim) => {
-                Wkb::MultiPolygon(MultiPolygon::new(buf, byte_order, dim))
+                Wkb::MultiPolygon(MultiPolygon::try_new(buf, byte_order, offset, dim)?)
             }
-            WKBType::GeometryCollection(dim) => {
-                Wkb::GeometryCollection(GeometryCollection::try_new(buf, byte_order, dim)?)
+            WKBType::GeometryCollection(dim) => Wkb::GeometryCollection(
+                GeometryCollection::try_new(buf, byte_order, offset, dim, depth)?,
+            ),
+            WKBType::PolyhedralSurface(dim) => {
+                Wkb::PolyhedralSurface(PolyhedralSurface::try_new(buf, byte_order, offset, dim)?)
+            }
+            WKBType::Tin(dim) => Wkb::Tin(Tin::try_new(buf, byte_order, offset, dim)?),
+            WKBType::Triangle(dim) => {
+                Wkb::Triangle(Triangle::try_new(buf, byte_order, offset, dim)?)
+            }
+            found => {
+                return Err(WKBError::CurveGeometry {
+                    found,
+                    offset,
+                    path: vec![],
+                })
             }
         };
         Ok(out)
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         use Wkb::*;
         match self {
@@ -57,9 +98,32 @@ impl<'a> Wkb<'a> {
             MultiLineString(g) => g.dimension(),
             MultiPolygon(g) => g.dimension(),
             GeometryCollection(g) => g.dimension(),
+            PolyhedralSurface(g) => g.dimension(),
+            Tin(g) => g.dimension(),
+            Triangle(g) => g.dimension(),
+        }
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        use Wkb::*;
+        match self {
+            Point(g) => g.srid(),
+            LineString(g) => g.srid(),
+            Polygon(g) => g.srid(),
+            MultiPoint(g) => g.srid(),
+            MultiLineString(g) => g.srid(),
+            MultiPolygon(g) => g.srid(),
+            GeometryCollection(g) => g.srid(),
+            PolyhedralSurface(g) => g.srid(),
+            Tin(g) => g.srid(),
+            Triangle(g) => g.srid(),
         }
     }
 
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
         use Wkb::*;
         match self {
@@ -70,6 +134,9 @@ impl<'a> Wkb<'a> {
             MultiLineString(g) => g.size(),
             MultiPolygon(g) => g.size(),
             GeometryCollection(g) => g.size(),
+            PolyhedralSurface(g) => g.size(),
+            Tin(g) => g.size(),
+            Triangle(g) => g.size(),
         }
     }
 }
@@ -109,7 +176,7 @@ impl<'a> GeometryTrait for Wkb<'a> {
     where
         Self: 'b;
     type TriangleType<'b>
-        = UnimplementedTriangle<f64>
+        = Triangle<'a>
     where
         Self: 'b;
     type LineType<'b>
@@ -133,7 +200,7 @@ impl<'a> GeometryTrait for Wkb<'a> {
         MultiPolygon<'a>,
         GeometryCollection<'a>,
         UnimplementedRect<f64>,
-        UnimplementedTriangle<f64>,
+        Triangle<'a>,
         UnimplementedLine<f64>,
     > {
         use geo_traits::GeometryType as B;
@@ -146,6 +213,10 @@ impl<'a> GeometryTrait for Wkb<'a> {
             A::MultiLineString(ls) => B::MultiLineString(ls),
             A::MultiPolygon(ls) => B::MultiPolygon(ls),
             A::GeometryCollection(gc) => B::GeometryCollection(gc),
+            A::PolyhedralSurface(ps) => B::MultiPolygon(ps.as_multi_polygon()),
+            A::Tin(tin) => B::MultiPolygon(tin.as_multi_polygon()),
+            A::Triangle(tri) if tri.is_empty() => B::Polygon(tri.as_polygon()),
+            A::Triangle(tri) => B::Triangle(tri),
         }
     }
 }
@@ -185,7 +256,7 @@ impl<'a> GeometryTrait for &'a Wkb<'a> {
     where
         Self: 'b;
     type TriangleType<'b>
-        = UnimplementedTriangle<f64>
+        = Triangle<'a>
     where
         Self: 'b;
     type LineType<'b>
@@ -209,7 +280,7 @@ impl<'a> GeometryTrait for &'a Wkb<'a> {
         MultiPolygon<'a>,
         GeometryCollection<'a>,
         UnimplementedRect<f64>,
-        UnimplementedTriangle<f64>,
+        Triangle<'a>,
         UnimplementedLine<f64>,
     > {
         use geo_traits::GeometryType as B;
@@ -222,6 +293,10 @@ impl<'a> GeometryTrait for &'a Wkb<'a> {
             A::MultiLineString(ls) => B::MultiLineString(ls),
             A::MultiPolygon(ls) => B::MultiPolygon(ls),
             A::GeometryCollection(gc) => B::GeometryCollection(gc),
+            A::PolyhedralSurface(ps) => B::MultiPolygon(ps.as_multi_polygon()),
+            A::Tin(tin) => B::MultiPolygon(tin.as_multi_polygon()),
+            A::Triangle(tri) if tri.is_empty() => B::Polygon(tri.as_polygon()),
+            A::Triangle(tri) => B::Triangle(tri),
         }
     }
 }
@@ -269,7 +344,7 @@ macro_rules! impl_specialization {
             where
                 Self: 'b;
             type TriangleType<'b>
-                = geo_traits::UnimplementedTriangle<f64>
+                = Triangle<'b>
             where
                 Self: 'b;
 
@@ -281,13 +356,13 @@ macro_rules! impl_specialization {
                 &self,
             ) -> geo_traits::GeometryType<
                 '_,
-                Point,
-                LineString,
-                Polygon,
-                MultiPoint,
-                MultiLineString,
-                MultiPolygon,
-                GeometryCollection,
+                Point<'_>,
+                LineString<'_>,
+                Polygon<'_>,
+                MultiPoint<'_>,
+                MultiLineString<'_>,
+                MultiPolygon<'_>,
+                GeometryCollection<'_>,
                 Self::RectType<'_>,
                 Self::TriangleType<'_>,
                 Self::LineType<'_>,
@@ -335,7 +410,7 @@ macro_rules! impl_specialization {
             where
                 Self: 'b;
             type TriangleType<'b>
-                = geo_traits::UnimplementedTriangle<f64>
+                = Triangle<'b>
             where
                 Self: 'b;
 
@@ -347,13 +422,13 @@ macro_rules! impl_specialization {
                 &self,
             ) -> geo_traits::GeometryType<
                 '_,
-                Point,
-                LineString,
-                Polygon,
-                MultiPoint,
-                MultiLineString,
-                MultiPolygon,
-                GeometryCollection,
+                Point<'_>,
+                LineString<'_>,
+                Polygon<'_>,
+                MultiPoint<'_>,
+                MultiLineString<'_>,
+                MultiPolygon<'_>,
+                GeometryCollection<'_>,
                 Self::RectType<'_>,
                 Self::TriangleType<'_>,
                 Self::LineType<'_>,
@@ -1,9 +1,9 @@
-use std::io::Cursor;
-
-use crate::common::WKBDimension;
+use crate::common::{WKBDimension, WKBType};
 use crate::error::WKBResult;
 use crate::reader::geometry::Wkb;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{
+    check_bounds, check_child_dimension, read_header, read_srid, read_u32_at,
+};
 use crate::Endianness;
 use geo_traits::{Dimensions, GeometryCollectionTrait};
 
@@ -16,53 +16,83 @@ pub struct GeometryCollection<'a> {
     /// A WKB object for each of the internal geometries
     geometries: Vec<Wkb<'a>>,
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> GeometryCollection<'a> {
-    pub fn try_new(buf: &'a [u8], byte_order: Endianness, dim: WKBDimension) -> WKBResult<Self> {
-        let mut offset = 0;
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
+    /// Parse the collection starting at `offset`, nested `depth` levels deep
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        mut offset: u64,
+        dim: WKBDimension,
+        depth: usize,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        if srid.is_some() {
             offset += 4;
         }
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES + offset);
-        let num_geometries = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+        let num_geometries = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
 
+        // - existing offset into buffer
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numGeometries
-        let mut geometry_offset = 1 + 4 + 4;
-        if has_srid {
-            geometry_offset += 4;
-        }
+        let mut geometry_offset = offset + 1 + 4 + 4;
+
+        // Each geometry takes at least 9 bytes, so check the count against the buffer before
+        // allocating.
+        check_bounds(buf, geometry_offset, 9 * num_geometries as u64)?;
 
+        let parent_type = WKBType::GeometryCollection(dim);
         let mut geometries = Vec::with_capacity(num_geometries);
-        for _ in 0..num_geometries {
-            let geometry = Wkb::try_new(&buf[geometry_offset..])?;
-            geometry_offset += geometry.size() as usize;
+        for i in 0..num_geometries {
+            let geometry = read_header(buf, geometry_offset)
+                .and_then(|(_, wkb_type)| {
+                    check_child_dimension(&parent_type, &wkb_type, geometry_offset)
+                })
+                .and_then(|_| Wkb::try_new_at(buf, geometry_offset, depth + 1))
+                .map_err(|err| err.in_part(i))?;
+            geometry_offset += geometry.size();
             geometries.push(geometry);
         }
 
         Ok(Self {
             geometries,
             dim,
-            has_srid,
+            srid,
         })
     }
 
+    /// Create a collection of geometries that have already been parsed
+    pub(crate) fn new(geometries: Vec<Wkb<'a>>, dim: WKBDimension, srid: Option<i32>) -> Self {
+        Self {
+            geometries,
+            dim,
+            srid,
+        }
+    }
+
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
 
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numGeometries
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
         self.geometries.iter().fold(header, |acc, x| acc + x.size())
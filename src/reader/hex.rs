@@ -0,0 +1,59 @@
+use crate::error::{WKBError, WKBResult};
+use crate::reader::Wkb;
+
+/// Parse a hex-encoded WKB or EWKB string into a geometry.
+///
+/// Both upper- and lower-case hex digits are accepted, as well as a leading `\x` as used by
+/// PostgreSQL for `bytea` values. The decoded bytes are written into `buf`, which is cleared
+/// first, and the returned geometry borrows from it. Reusing the same `buf` across calls avoids
+/// allocating for each geometry.
+///
+/// ```
+/// use wkb::reader::read_hex_wkb;
+///
+/// let mut buf = Vec::new();
+/// let geometry =
+///     read_hex_wkb("0101000020E6100000000000000000F03F0000000000000040", &mut buf).unwrap();
+/// assert_eq!(geometry.srid(), Some(4326));
+/// ```
+pub fn read_hex_wkb<'a>(hex: &str, buf: &'a mut Vec<u8>) -> WKBResult<Wkb<'a>> {
+    decode_hex(hex, buf)?;
+    Wkb::try_new(buf)
+}
+
+/// Decode a hex string into `buf`, replacing its contents
+pub(crate) fn decode_hex(hex: &str, buf: &mut Vec<u8>) -> WKBResult<()> {
+    let (prefix, digits) = match hex.strip_prefix("\\x") {
+        Some(digits) => (2, digits.as_bytes()),
+        None => (0, hex.as_bytes()),
+    };
+
+    buf.clear();
+    buf.reserve(digits.len() / 2);
+    for (i, pair) in digits.chunks(2).enumerate() {
+        let offset = prefix + 2 * i;
+        let high = hex_digit(pair[0], offset)?;
+        let low = match pair.get(1) {
+            Some(digit) => hex_digit(*digit, offset + 1)?,
+            None => {
+                return Err(WKBError::InvalidHex {
+                    offset: hex.len() as u64,
+                })
+            }
+        };
+        buf.push((high << 4) | low);
+    }
+    Ok(())
+}
+
+/// The value of a single hex digit at `offset` in the hex string
+fn hex_digit(digit: u8, offset: usize) -> WKBResult<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(WKBError::InvalidHex {
+            offset: offset as u64,
+        }),
+    }
+}
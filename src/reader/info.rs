@@ -0,0 +1,154 @@
+use geo_traits::{LineStringTrait, MultiPointTrait, PointTrait};
+
+use crate::common::{WKBDimension, WKBFlavor, WKBGeometryCode, WKBType};
+use crate::error::WKBResult;
+use crate::reader::geometry::Wkb;
+use crate::reader::lazy::LazyWkb;
+use crate::reader::util::read_u32_at;
+use crate::Endianness;
+
+/// A summary of a WKB geometry, read by [`read_wkb_info`][crate::reader::read_wkb_info]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WkbInfo {
+    wkb_type: WKBType,
+    byte_order: Endianness,
+    flavor: WKBFlavor,
+    num_parts: Option<usize>,
+    num_rings: usize,
+    num_coords: usize,
+    size: u64,
+}
+
+impl WkbInfo {
+    /// Scan the geometry at the start of `buf`
+    pub(crate) fn try_new(buf: &[u8]) -> WKBResult<Self> {
+        let geometry = LazyWkb::try_new_at(buf, 0, 0)?;
+        let geometry_code = WKBGeometryCode::new(read_u32_at(buf, geometry.byte_order(), 1)?);
+        let flavor = if geometry_code.is_extended() {
+            WKBFlavor::Extended {
+                srid: geometry.srid(),
+            }
+        } else {
+            WKBFlavor::Iso
+        };
+        let num_parts = match geometry.wkb_type() {
+            WKBType::MultiPoint(_)
+            | WKBType::MultiLineString(_)
+            | WKBType::MultiPolygon(_)
+            | WKBType::GeometryCollection(_)
+            | WKBType::PolyhedralSurface(_)
+            | WKBType::Tin(_) => Some(geometry.parts()?.num_parts()),
+            _ => None,
+        };
+
+        let mut counts = Counts::default();
+        let size = scan(&geometry, &mut counts)?;
+        Ok(Self {
+            wkb_type: geometry.wkb_type(),
+            byte_order: geometry.byte_order(),
+            flavor,
+            num_parts,
+            num_rings: counts.num_rings,
+            num_coords: counts.num_coords,
+            size,
+        })
+    }
+
+    /// The type of the geometry
+    pub fn wkb_type(&self) -> WKBType {
+        self.wkb_type
+    }
+
+    /// The dimension of the geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.wkb_type.dimension()
+    }
+
+    /// The byte order of the geometry
+    pub fn byte_order(&self) -> Endianness {
+        self.byte_order
+    }
+
+    /// The flavor of the geometry, with any SRID it embeds.
+    ///
+    /// A geometry whose code has none of the extended WKB flags is reported as ISO WKB, even
+    /// though a two-dimensional geometry without an SRID is encoded the same way in both flavors.
+    pub fn flavor(&self) -> WKBFlavor {
+        self.flavor
+    }
+
+    /// The SRID embedded in the geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.flavor.srid()
+    }
+
+    /// The number of parts of a MultiPoint, MultiLineString, MultiPolygon, GeometryCollection,
+    /// PolyhedralSurface or TIN, or `None` for other types
+    pub fn num_parts(&self) -> Option<usize> {
+        self.num_parts
+    }
+
+    /// The total number of rings of all the Polygons and Triangles in the geometry
+    pub fn num_rings(&self) -> usize {
+        self.num_rings
+    }
+
+    /// The total number of coordinates in the geometry, not counting empty Points
+    pub fn num_coords(&self) -> usize {
+        self.num_coords
+    }
+
+    /// The number of bytes in the geometry, including any header
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+#[derive(Default)]
+struct Counts {
+    num_rings: usize,
+    num_coords: usize,
+}
+
+/// Add the rings and coordinates of `geometry` to `counts`, returning its size
+fn scan(geometry: &LazyWkb, counts: &mut Counts) -> WKBResult<u64> {
+    let size = match geometry.wkb_type() {
+        // These are parsed without allocating
+        WKBType::Point(_) | WKBType::LineString(_) | WKBType::MultiPoint(_) => {
+            let parsed = geometry.parse()?;
+            counts.num_coords += match &parsed {
+                Wkb::Point(point) => point.coord().map_or(0, |_| 1),
+                Wkb::LineString(line_string) => line_string.num_coords(),
+                Wkb::MultiPoint(multi_point) => multi_point
+                    .points()
+                    .filter(|point| point.coord().is_some())
+                    .count(),
+                _ => unreachable!(),
+            };
+            parsed.size()
+        }
+        WKBType::Polygon(_) | WKBType::Triangle(_) => {
+            let mut size = geometry.header_size() + 4;
+            for ring in geometry.rings()? {
+                let ring = ring?;
+                counts.num_rings += 1;
+                counts.num_coords += ring.num_coords();
+                size += ring.size();
+            }
+            size
+        }
+        _ => {
+            let mut size = geometry.header_size() + 4;
+            let mut parts = geometry.parts()?;
+            let mut i = 0;
+            while let Some(part) = parts.next() {
+                let part_size = scan(&part?, counts).map_err(|err| err.in_part(i))?;
+                parts.skip_pending(part_size);
+                size += part_size;
+                i += 1;
+            }
+            size
+        }
+    };
+    Ok(size)
+}
@@ -0,0 +1,351 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::geometry::Wkb;
+use crate::reader::linearring::WKBLinearRing;
+use crate::reader::point::point_size;
+use crate::reader::util::{
+    check_bounds, check_child_dimension, check_depth, read_child_header, read_header, read_srid,
+    read_u32_at,
+};
+use crate::Endianness;
+
+/// A WKB geometry whose contents are read on demand.
+///
+/// Constructing a `LazyWkb` only reads the header of the geometry. Parts and rings are read one at
+/// a time through the [`parts`][LazyWkb::parts] and [`rings`][LazyWkb::rings] iterators, and
+/// nothing is allocated on the heap. In exchange, there is no random access to parts: reaching
+/// the `n`th part walks all the parts before it.
+///
+/// The contents of the geometry are only validated as they are read. Use
+/// [`parse`][LazyWkb::parse] to parse and validate the whole geometry into a [`Wkb`].
+///
+/// ```
+/// use wkb::reader::read_wkb_lazy;
+/// use wkb::WKBType;
+///
+/// let multi_point = geo_types::MultiPoint::from(vec![(1., 2.), (3., 4.)]);
+/// let mut buf = Vec::new();
+/// wkb::writer::write_geometry(&mut buf, &multi_point, Default::default()).unwrap();
+///
+/// let geometry = read_wkb_lazy(&buf).unwrap();
+/// assert!(matches!(geometry.wkb_type(), WKBType::MultiPoint(_)));
+///
+/// let first = geometry.parts().unwrap().next().unwrap().unwrap();
+/// assert!(matches!(first.wkb_type(), WKBType::Point(_)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LazyWkb<'a> {
+    buf: &'a [u8],
+    /// The offset into the buffer where this geometry starts
+    offset: u64,
+    byte_order: Endianness,
+    wkb_type: WKBType,
+    srid: Option<i32>,
+    /// The number of levels this geometry is nested within the top-level geometry
+    depth: usize,
+}
+
+impl<'a> LazyWkb<'a> {
+    /// Read the header of the geometry starting at `offset` within `buf`, nested `depth` levels
+    /// deep
+    pub(crate) fn try_new_at(buf: &'a [u8], offset: u64, depth: usize) -> WKBResult<Self> {
+        check_depth(depth, offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        if wkb_type.is_curve() {
+            return Err(WKBError::CurveGeometry {
+                found: wkb_type,
+                offset,
+                path: vec![],
+            });
+        }
+        let srid = read_srid(buf, byte_order, offset)?;
+        Ok(Self {
+            buf,
+            offset,
+            byte_order,
+            wkb_type,
+            srid,
+            depth,
+        })
+    }
+
+    /// The type of this geometry
+    pub fn wkb_type(&self) -> WKBType {
+        self.wkb_type
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.wkb_type.dimension()
+    }
+
+    /// The byte order of this geometry
+    pub fn byte_order(&self) -> Endianness {
+        self.byte_order
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// Parse and validate the whole geometry.
+    ///
+    /// This is the same as reading the geometry with [`read_wkb`][crate::reader::read_wkb], and
+    /// allocates for Polygons and for multi-geometries other than MultiPoints.
+    pub fn parse(&self) -> WKBResult<Wkb<'a>> {
+        Wkb::try_new_at(self.buf, self.offset, self.depth)
+    }
+
+    /// The number of bytes in this geometry, including any header.
+    ///
+    /// This walks the whole geometry without allocating, and errors if it extends past the end
+    /// of the buffer.
+    pub fn size(&self) -> WKBResult<u64> {
+        let body_offset = self.body_offset();
+        let end = match self.wkb_type {
+            WKBType::Point(dim) => {
+                let size = point_size(dim, self.srid.is_some());
+                check_bounds(self.buf, self.offset, size)?;
+                self.offset + size
+            }
+            WKBType::LineString(dim) => {
+                let num_points = read_u32_at(self.buf, self.byte_order, body_offset)?;
+                let coords_size = dim.size() as u64 * 8 * num_points as u64;
+                check_bounds(self.buf, body_offset + 4, coords_size)?;
+                body_offset + 4 + coords_size
+            }
+            WKBType::Polygon(_) | WKBType::Triangle(_) => self.rings()?.end_offset()?,
+            _ => self.parts()?.end_offset()?,
+        };
+        Ok(end - self.offset)
+    }
+
+    /// Iterate over the parts of a MultiPoint, MultiLineString, MultiPolygon, GeometryCollection,
+    /// PolyhedralSurface or TIN.
+    ///
+    /// Each part is read when the iterator reaches it. The iterator stops after yielding an
+    /// error.
+    pub fn parts(&self) -> WKBResult<Parts<'a>> {
+        let part_type = match self.wkb_type {
+            WKBType::MultiPoint(dim) => Some(WKBType::Point(dim)),
+            WKBType::MultiLineString(dim) => Some(WKBType::LineString(dim)),
+            WKBType::MultiPolygon(dim) | WKBType::PolyhedralSurface(dim) => {
+                Some(WKBType::Polygon(dim))
+            }
+            WKBType::Tin(dim) => Some(WKBType::Triangle(dim)),
+            WKBType::GeometryCollection(_) => None,
+            found => {
+                return Err(WKBError::IncorrectType(
+                    format!("{:?} has no parts", found).into(),
+                ))
+            }
+        };
+        let body_offset = self.body_offset();
+        let num_parts = read_u32_at(self.buf, self.byte_order, body_offset)? as usize;
+        Ok(Parts {
+            buf: self.buf,
+            parent_type: self.wkb_type,
+            part_type,
+            depth: self.depth + 1,
+            offset: body_offset + 4,
+            num_parts,
+            index: 0,
+            pending: None,
+            failed: false,
+        })
+    }
+
+    /// Iterate over the rings of a Polygon or Triangle.
+    ///
+    /// Each ring is read when the iterator reaches it. The iterator stops after yielding an
+    /// error.
+    pub fn rings(&self) -> WKBResult<Rings<'a>> {
+        let (WKBType::Polygon(dim) | WKBType::Triangle(dim)) = self.wkb_type else {
+            return Err(WKBError::IncorrectType(
+                format!("{:?} has no rings", self.wkb_type).into(),
+            ));
+        };
+        let body_offset = self.body_offset();
+        let num_rings = read_u32_at(self.buf, self.byte_order, body_offset)? as usize;
+        Ok(Rings {
+            buf: self.buf,
+            byte_order: self.byte_order,
+            dim,
+            offset: body_offset + 4,
+            num_rings,
+            index: 0,
+            failed: false,
+        })
+    }
+
+    /// The number of bytes in the header, including any SRID
+    pub(crate) fn header_size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: SRID, if any
+        let mut size = 1 + 4;
+        if self.srid.is_some() {
+            size += 4;
+        }
+        size
+    }
+
+    /// The offset of the content after the header and any SRID
+    fn body_offset(&self) -> u64 {
+        self.offset + self.header_size()
+    }
+}
+
+/// An iterator over the parts of a [`LazyWkb`], created by [`LazyWkb::parts`]
+#[derive(Debug, Clone)]
+pub struct Parts<'a> {
+    buf: &'a [u8],
+    parent_type: WKBType,
+    /// The type every part must have, or `None` for the parts of a GeometryCollection
+    part_type: Option<WKBType>,
+    /// The nesting depth of the parts
+    depth: usize,
+    /// The offset of the next part, once any pending part has been skipped
+    offset: u64,
+    num_parts: usize,
+    index: usize,
+    /// The last part yielded, which is only walked to find the next offset once it is needed
+    pending: Option<LazyWkb<'a>>,
+    failed: bool,
+}
+
+impl<'a> Parts<'a> {
+    /// The total number of parts, including any that have already been read
+    pub fn num_parts(&self) -> usize {
+        self.num_parts
+    }
+
+    /// Record the size of the last part yielded, for a caller that has already walked it, so that
+    /// it is not walked again to find the next part
+    pub(crate) fn skip_pending(&mut self, size: u64) {
+        if self.pending.take().is_some() {
+            self.offset += size;
+        }
+    }
+
+    fn read_next(&mut self) -> WKBResult<LazyWkb<'a>> {
+        if let Some(pending) = self.pending.take() {
+            self.offset += pending.size().map_err(|err| err.in_part(self.index - 1))?;
+        }
+
+        let offset = self.offset;
+        let part = match self.part_type {
+            Some(part_type) => read_child_header(self.buf, offset, part_type)
+                .and_then(|_| LazyWkb::try_new_at(self.buf, offset, self.depth)),
+            None => read_header(self.buf, offset)
+                .and_then(|(_, wkb_type)| {
+                    check_child_dimension(&self.parent_type, &wkb_type, offset)
+                })
+                .and_then(|_| LazyWkb::try_new_at(self.buf, offset, self.depth)),
+        }
+        .and_then(|part| match part.srid {
+            Some(srid) if matches!(self.parent_type, WKBType::MultiPoint(_)) => {
+                Err(WKBError::UnexpectedSrid {
+                    srid,
+                    offset,
+                    path: vec![],
+                })
+            }
+            _ => Ok(part),
+        })
+        .map_err(|err| err.in_part(self.index))?;
+
+        self.pending = Some(part);
+        self.index += 1;
+        Ok(part)
+    }
+
+    /// Walk the remaining parts, returning the offset of the end of the last part
+    fn end_offset(mut self) -> WKBResult<u64> {
+        for part in &mut self {
+            part?;
+        }
+        if let Some(pending) = self.pending.take() {
+            self.offset += pending.size().map_err(|err| err.in_part(self.index - 1))?;
+        }
+        Ok(self.offset)
+    }
+}
+
+impl<'a> Iterator for Parts<'a> {
+    type Item = WKBResult<LazyWkb<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.index >= self.num_parts {
+            return None;
+        }
+        let part = self.read_next();
+        self.failed = part.is_err();
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            (0, Some(self.num_parts - self.index))
+        }
+    }
+}
+
+/// An iterator over the rings of a [`LazyWkb`], created by [`LazyWkb::rings`]
+#[derive(Debug, Clone)]
+pub struct Rings<'a> {
+    buf: &'a [u8],
+    byte_order: Endianness,
+    dim: WKBDimension,
+    /// The offset of the next ring
+    offset: u64,
+    num_rings: usize,
+    index: usize,
+    failed: bool,
+}
+
+impl<'a> Rings<'a> {
+    /// The total number of rings, including any that have already been read
+    pub fn num_rings(&self) -> usize {
+        self.num_rings
+    }
+
+    /// Walk the remaining rings, returning the offset of the end of the last ring
+    fn end_offset(mut self) -> WKBResult<u64> {
+        for ring in &mut self {
+            ring?;
+        }
+        Ok(self.offset)
+    }
+}
+
+impl<'a> Iterator for Rings<'a> {
+    type Item = WKBResult<WKBLinearRing<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.index >= self.num_rings {
+            return None;
+        }
+        let ring = WKBLinearRing::try_new(self.buf, self.byte_order, self.offset, self.dim)
+            .map_err(|err| err.in_part(self.index));
+        match ring {
+            Ok(ring) => {
+                self.offset += ring.size();
+                self.index += 1;
+            }
+            Err(_) => self.failed = true,
+        }
+        Some(ring)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            (0, Some(self.num_rings - self.index))
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Approximate curve geometries with linear geometries.
+
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use geo_traits::{CoordTrait, LineStringTrait};
+
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::{CircularString, CompoundCurve, Curve, CurvePolygon, CurvedWkb, Surface};
+use crate::writer::{write_geometry, write_polygon, WriteOptions};
+use crate::Endianness;
+
+/// The most segments that all the arcs of a geometry are approximated with in total, so that
+/// arcs with a huge radius cannot exhaust memory
+const MAX_ARC_SEGMENTS: usize = 1 << 20;
+
+/// Check that a linearization tolerance is a positive, finite number
+pub(crate) fn validate_tolerance(tolerance: f64) -> WKBResult<()> {
+    if tolerance > 0.0 && tolerance.is_finite() {
+        Ok(())
+    } else {
+        Err(WKBError::General(format!(
+            "Linearization tolerance must be positive and finite, got {}",
+            tolerance
+        )))
+    }
+}
+
+/// Write the linear approximation of `geom` as little-endian ISO WKB
+pub(crate) fn linearize_curved_wkb(geom: &CurvedWkb, tolerance: f64) -> WKBResult<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut linearizer = Linearizer {
+        tolerance,
+        segments: 0,
+    };
+    linearizer.geometry(&mut out, geom)?;
+    Ok(out)
+}
+
+/// Approximates the curves of a geometry, keeping count of the arc segments written
+struct Linearizer {
+    tolerance: f64,
+    /// The number of segments that arcs have been approximated with so far
+    segments: usize,
+}
+
+impl Linearizer {
+    fn geometry(&mut self, out: &mut Vec<u8>, geom: &CurvedWkb) -> WKBResult<()> {
+        let dim = geom.dimension();
+        match geom {
+            CurvedWkb::Linear(g) => {
+                write_geometry(out, g, WriteOptions::default())?;
+            }
+            CurvedWkb::CircularString(g) => {
+                write_line_string(out, dim, &self.circular_string_coords(g)?)?;
+            }
+            CurvedWkb::CompoundCurve(g) => {
+                write_line_string(out, dim, &self.compound_curve_coords(g)?)?;
+            }
+            CurvedWkb::CurvePolygon(g) => self.curve_polygon(out, g)?,
+            CurvedWkb::MultiCurve(g) => {
+                write_header(out, WKBType::MultiLineString(dim), g.curves().len())?;
+                for curve in g.curves() {
+                    write_line_string(out, dim, &self.curve_coords(curve)?)?;
+                }
+            }
+            CurvedWkb::MultiSurface(g) => {
+                write_header(out, WKBType::MultiPolygon(dim), g.surfaces().len())?;
+                for surface in g.surfaces() {
+                    match surface {
+                        Surface::Polygon(p) => write_polygon(out, p, WriteOptions::default())?,
+                        Surface::CurvePolygon(p) => self.curve_polygon(out, p)?,
+                    }
+                }
+            }
+            CurvedWkb::GeometryCollection(g) => {
+                write_header(out, WKBType::GeometryCollection(dim), g.geometries().len())?;
+                for geometry in g.geometries() {
+                    self.geometry(out, geometry)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn curve_polygon(&mut self, out: &mut Vec<u8>, geom: &CurvePolygon) -> WKBResult<()> {
+        let dim = geom.dimension();
+        write_header(out, WKBType::Polygon(dim), geom.rings().len())?;
+        for ring in geom.rings() {
+            write_coords(out, dim, &self.curve_coords(ring)?)?;
+        }
+        Ok(())
+    }
+
+    /// The coordinates of a linear approximation of `curve`, flattened into a single `Vec`
+    fn curve_coords(&mut self, curve: &Curve) -> WKBResult<Vec<f64>> {
+        match curve {
+            Curve::LineString(g) => Ok(line_string_coords(g)),
+            Curve::CircularString(g) => self.circular_string_coords(g),
+            Curve::CompoundCurve(g) => self.compound_curve_coords(g),
+        }
+    }
+
+    fn compound_curve_coords(&mut self, geom: &CompoundCurve) -> WKBResult<Vec<f64>> {
+        let dim_size = geom.dimension().size();
+        let mut out = Vec::new();
+        for segment in geom.segments() {
+            let coords = self.curve_coords(segment)?;
+            // Each segment starts at the end point of the previous segment
+            let skip = if out.is_empty() { 0 } else { dim_size };
+            out.extend_from_slice(coords.get(skip..).unwrap_or_default());
+        }
+        Ok(out)
+    }
+
+    fn circular_string_coords(&mut self, geom: &CircularString) -> WKBResult<Vec<f64>> {
+        let dim_size = geom.dimension().size();
+        let points = line_string_coords(geom);
+        let num_points = points.len() / dim_size;
+        let point = |i: usize| &points[i * dim_size..(i + 1) * dim_size];
+        if num_points < 3 {
+            return Ok(points);
+        }
+
+        let mut out = point(0).to_vec();
+        let mut i = 0;
+        while i + 2 < num_points {
+            self.push_arc(&mut out, point(i), point(i + 1), point(i + 2))?;
+            i += 2;
+        }
+        // Any control points after the last complete arc are joined with straight segments
+        out.extend_from_slice(&points[(i + 1) * dim_size..]);
+        Ok(out)
+    }
+
+    /// Append the points of the arc through `a`, `b` and `c`, excluding `a`, to `out`
+    fn push_arc(&mut self, out: &mut Vec<f64>, a: &[f64], b: &[f64], c: &[f64]) -> WKBResult<()> {
+        let full_circle = a[0] == c[0] && a[1] == c[1];
+        let (center, ccw) = if full_circle {
+            // The middle control point of a full circle is opposite the start point
+            ([(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0], true)
+        } else {
+            // Translate so that `a` is at the origin, for numerical stability
+            let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+            let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+            let d = 2.0 * (bx * cy - by * cx);
+            if d == 0.0 || !d.is_finite() {
+                // The control points are collinear
+                out.extend_from_slice(b);
+                out.extend_from_slice(c);
+                return Ok(());
+            }
+            let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+            let center = [
+                a[0] + (cy * b2 - by * c2) / d,
+                a[1] + (bx * c2 - cx * b2) / d,
+            ];
+            (center, d > 0.0)
+        };
+
+        let radius = (a[0] - center[0]).hypot(a[1] - center[1]);
+        let angle = |p: &[f64]| (p[1] - center[1]).atan2(p[0] - center[0]);
+        let sweep = |from: f64, to: f64| {
+            if ccw {
+                (to - from).rem_euclid(TAU)
+            } else {
+                -(from - to).rem_euclid(TAU)
+            }
+        };
+        let (start_a, start_b) = (angle(a), angle(b));
+        let (sweep_ab, sweep_bc) = if full_circle {
+            (PI, PI)
+        } else {
+            (sweep(start_a, start_b), sweep(start_b, angle(c)))
+        };
+
+        // The largest angle whose chord is within `self.tolerance` of the arc, from the sagitta
+        // `r * (1 - cos(step / 2)) = 2 * r * sin^2(step / 4)`
+        let max_step = if self.tolerance < 2.0 * radius {
+            4.0 * (self.tolerance / (2.0 * radius)).sqrt().asin()
+        } else {
+            PI
+        }
+        .min(FRAC_PI_2);
+
+        let arc = Arc {
+            center,
+            radius,
+            max_step,
+        };
+        arc.push(out, a, b, start_a, sweep_ab, &mut self.segments)?;
+        arc.push(out, b, c, start_b, sweep_bc, &mut self.segments)
+    }
+}
+
+/// Convert a part or point count to the `u32` that WKB stores it as
+fn count_u32(count: usize) -> WKBResult<u32> {
+    count.try_into().map_err(|_| {
+        WKBError::General(format!(
+            "Linearized geometry has {} parts or points, more than WKB can store",
+            count
+        ))
+    })
+}
+
+/// Write the byte order, geometry code and part count of a linear geometry
+fn write_header(out: &mut Vec<u8>, wkb_type: WKBType, num_parts: usize) -> WKBResult<()> {
+    out.write_u8(Endianness::LittleEndian.into())?;
+    out.write_u32::<LittleEndian>(wkb_type.into())?;
+    out.write_u32::<LittleEndian>(count_u32(num_parts)?)?;
+    Ok(())
+}
+
+/// Write the point count and values of a flat coordinate sequence
+fn write_coords(out: &mut Vec<u8>, dim: WKBDimension, coords: &[f64]) -> WKBResult<()> {
+    out.write_u32::<LittleEndian>(count_u32(coords.len() / dim.size())?)?;
+    for value in coords {
+        out.write_f64::<LittleEndian>(*value)?;
+    }
+    Ok(())
+}
+
+fn write_line_string(out: &mut Vec<u8>, dim: WKBDimension, coords: &[f64]) -> WKBResult<()> {
+    out.write_u8(Endianness::LittleEndian.into())?;
+    out.write_u32::<LittleEndian>(WKBType::LineString(dim).into())?;
+    write_coords(out, dim, coords)
+}
+
+fn line_string_coords(geom: &impl LineStringTrait<T = f64>) -> Vec<f64> {
+    let dim_size = geom.dim().size();
+    let mut out = Vec::with_capacity(geom.num_coords() * dim_size);
+    for coord in geom.coords() {
+        out.extend((0..dim_size).map(|n| coord.nth_or_panic(n)));
+    }
+    out
+}
+
+/// A circle that control points are placed on
+struct Arc {
+    center: [f64; 2],
+    radius: f64,
+    max_step: f64,
+}
+
+impl Arc {
+    /// Append the points of the part of the arc from `from` to `to`, excluding `from`.
+    ///
+    /// Any Z and M values are interpolated linearly along the arc. The segments are added to
+    /// `total`, and an error is returned if that exceeds [`MAX_ARC_SEGMENTS`].
+    fn push(
+        &self,
+        out: &mut Vec<f64>,
+        from: &[f64],
+        to: &[f64],
+        start: f64,
+        sweep: f64,
+        total: &mut usize,
+    ) -> WKBResult<()> {
+        let segments = (sweep.abs() / self.max_step).ceil().max(1.0);
+        if segments > (MAX_ARC_SEGMENTS - *total) as f64 {
+            return Err(WKBError::General(format!(
+                "Linearizing the arcs of this geometry needs more than {} segments",
+                MAX_ARC_SEGMENTS
+            )));
+        }
+        let segments = segments as usize;
+        *total += segments;
+        for k in 1..segments {
+            let t = k as f64 / segments as f64;
+            let angle = start + sweep * t;
+            out.push(self.center[0] + self.radius * angle.cos());
+            out.push(self.center[1] + self.radius * angle.sin());
+            out.extend((2..from.len()).map(|n| from[n] + (to[n] - from[n]) * t));
+        }
+        out.extend_from_slice(to);
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::WKBDimension;
+use crate::error::WKBResult;
 use crate::reader::coord::Coord;
-use crate::reader::util::ReadBytesExt;
+use crate::reader::flat_coords::{CoordRun, FlatCoords};
+use crate::reader::util::{check_bounds, read_u32_at};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::LineStringTrait;
@@ -35,18 +35,24 @@ pub struct WKBLinearRing<'a> {
 }
 
 impl<'a> WKBLinearRing<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: WKBDimension) -> Self {
-        let mut reader = Cursor::new(buf);
-        reader.set_position(offset);
-        let num_points = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let num_points = read_u32_at(buf, byte_order, offset)? as usize;
+
+        // Validate that all coordinates are contained in the buffer
+        check_bounds(buf, offset + 4, dim.size() as u64 * 8 * num_points as u64)?;
 
-        Self {
+        Ok(Self {
             buf,
             byte_order,
             offset,
             num_points,
             dim,
-        }
+        })
     }
 
     /// The number of bytes in this object, including any header
@@ -63,6 +69,26 @@ impl<'a> WKBLinearRing<'a> {
     pub fn coord_offset(&self, i: u64) -> u64 {
         self.offset + 4 + (self.dim.size() as u64 * 8 * i)
     }
+
+    /// The dimension of this linear ring
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The coordinates of this linear ring, for decoding them at once
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        FlatCoords::new(vec![self.coord_run()], self.dim)
+    }
+
+    pub(crate) fn coord_run(&self) -> CoordRun<'a> {
+        CoordRun::new(
+            self.buf,
+            self.byte_order,
+            self.coord_offset(0),
+            self.num_points,
+            self.dim,
+        )
+    }
 }
 
 impl<'a> LineStringTrait for WKBLinearRing<'a> {
@@ -1,8 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::WKBDimension;
+use crate::error::WKBResult;
 use crate::reader::coord::Coord;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::flat_coords::{CoordRun, FlatCoords};
+use crate::reader::util::{check_bounds, read_srid, read_u32_at};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::LineStringTrait;
@@ -24,28 +24,40 @@ pub struct LineString<'a> {
     /// LineString contained within a MultiLineString
     offset: u64,
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> LineString<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, mut offset: u64, dim: WKBDimension) -> Self {
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        mut offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        if srid.is_some() {
             offset += 4;
         }
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES + offset);
-        let num_points = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+        let num_points = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
 
-        Self {
+        let line_string = Self {
             buf,
             byte_order,
             num_points,
             offset,
             dim,
-            has_srid,
-        }
+            srid,
+        };
+
+        // Validate that all coordinates are contained in the buffer
+        check_bounds(
+            buf,
+            line_string.coord_offset(0),
+            dim.size() as u64 * 8 * num_points as u64,
+        )?;
+
+        Ok(line_string)
     }
 
     /// The number of bytes in this object, including any header
@@ -57,7 +69,7 @@ impl<'a> LineString<'a> {
         // - 4: numPoints
         // - 2 * 8 * self.num_points: two f64s for each coordinate
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
         header + (self.dim.size() as u64 * 8 * self.num_points as u64)
@@ -68,9 +80,30 @@ impl<'a> LineString<'a> {
         self.offset + 1 + 4 + 4 + (self.dim.size() as u64 * 8 * i)
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The coordinates of this LineString, for decoding them at once
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        FlatCoords::new(vec![self.coord_run()], self.dim)
+    }
+
+    pub(crate) fn coord_run(&self) -> CoordRun<'a> {
+        CoordRun::new(
+            self.buf,
+            self.byte_order,
+            self.coord_offset(0),
+            self.num_points,
+            self.dim,
+        )
+    }
 }
 
 impl<'a> LineStringTrait for LineString<'a> {
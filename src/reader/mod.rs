@@ -3,35 +3,75 @@
 // Each of the data structures in this module is intended to mirror the [WKB
 // spec](https://portal.ogc.org/files/?artifact_id=25355).
 
+mod bounds;
+mod circularstring;
+mod compoundcurve;
 mod coord;
+mod curve;
+mod curved_geometry_collection;
+mod curvepolygon;
+mod flat_coords;
 mod geometry;
 mod geometry_collection;
+mod hex;
+mod info;
+mod lazy;
+mod linearize;
 mod linearring;
 mod linestring;
+mod multicurve;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod multisurface;
 mod point;
 mod polygon;
-mod util;
+mod polyhedralsurface;
+mod stream;
+mod tin;
+mod triangle;
+pub(crate) mod util;
 
-use geometry::Wkb;
-use geometry_collection::GeometryCollection;
-use linestring::LineString;
-use multilinestring::MultiLineString;
-use multipoint::MultiPoint;
-use multipolygon::MultiPolygon;
-use point::Point;
-use polygon::Polygon;
+pub use bounds::{BoundsCoord, WKBBounds};
+pub use circularstring::CircularString;
+pub use compoundcurve::CompoundCurve;
+pub use coord::Coord;
+pub use curve::{Curve, CurvedWkb, Surface};
+pub use curved_geometry_collection::CurvedGeometryCollection;
+pub use curvepolygon::CurvePolygon;
+pub use flat_coords::FlatCoords;
+pub use geometry::Wkb;
+pub use geometry_collection::GeometryCollection;
+pub use hex::read_hex_wkb;
+pub use info::WkbInfo;
+pub use lazy::{LazyWkb, Parts, Rings};
+pub use linearring::WKBLinearRing;
+pub use linestring::LineString;
+pub use multicurve::MultiCurve;
+pub use multilinestring::MultiLineString;
+pub use multipoint::MultiPoint;
+pub use multipolygon::MultiPolygon;
+pub use multisurface::MultiSurface;
+pub use point::Point;
+pub use polygon::Polygon;
+pub use polyhedralsurface::PolyhedralSurface;
+pub use stream::{WKBFraming, WKBStreamReader};
+pub use tin::Tin;
+pub use triangle::Triangle;
 
-use geo_traits::GeometryTrait;
+use crate::error::{WKBError, WKBResult};
 
-use crate::error::WKBResult;
+/// The deepest level of nesting that is read, where the top-level geometry is at level zero.
+///
+/// Only GeometryCollections can nest geometries arbitrarily deep. A geometry nested more deeply
+/// returns [`WKBError::TooDeeplyNested`] rather than overflowing the stack.
+pub const MAX_NESTING_DEPTH: usize = 256;
 
 /// Parse a WKB byte slice into a geometry.
 ///
-/// This returns an opaque object that implements [`GeometryTrait`]. Use methods provided by
-/// [`geo_traits`] to access the underlying data.
+/// This returns a [`Wkb`] object that implements [`GeometryTrait`][geo_traits::GeometryTrait].
+/// Use methods provided by [`geo_traits`] to access the underlying data, and [`Wkb::srid`] to
+/// access any SRID embedded in an EWKB buffer.
 ///
 /// The contained [dimension][geo_traits::Dimensions] will never be `Unknown`.
 ///
@@ -46,6 +86,133 @@ use crate::error::WKBResult;
 /// access** but **not zero-copy**. This is because the raw WKB buffer is not 8-byte aligned, so
 /// when accessing a coordinate the underlying bytes need to be copied into a newly-allocated
 /// `f64`.
-pub fn read_wkb(buf: &[u8]) -> WKBResult<impl GeometryTrait<T = f64> + use<'_>> {
+pub fn read_wkb(buf: &[u8]) -> WKBResult<Wkb<'_>> {
     Wkb::try_new(buf)
 }
+
+/// Read the header of a WKB byte slice, leaving its contents to be read on demand.
+///
+/// Unlike [`read_wkb`], this does not walk the geometry up front and never allocates, so it is
+/// cheap even for multi-geometries with many parts. Parts are read one at a time through
+/// [`LazyWkb::parts`], and [`LazyWkb::parse`] parses the whole geometry as [`read_wkb`] does.
+///
+/// Returns [`WKBError::CurveGeometry`] for curve types, like [`read_wkb`].
+pub fn read_wkb_lazy(buf: &[u8]) -> WKBResult<LazyWkb<'_>> {
+    LazyWkb::try_new_at(buf, 0, 0)
+}
+
+/// Compute the bounding box of a WKB or EWKB geometry by scanning its buffer once.
+///
+/// This does not build the reader structure of the geometry and never allocates. The result has
+/// the minimum and maximum of the x, y and any z and m values, and implements
+/// [`RectTrait`][geo_traits::RectTrait]. Every coordinate is included, so the result covers the
+/// whole buffer even for an invalid geometry. NaN values, such as those of empty points, are
+/// skipped.
+///
+/// Returns `None` for a geometry with no coordinates.
+///
+/// ```
+/// use geo_traits::{CoordTrait, RectTrait};
+/// use wkb::reader::read_wkb_bounds;
+///
+/// let line_string = geo_types::LineString::from(vec![(1., 5.), (3., 2.)]);
+/// let mut buf = Vec::new();
+/// wkb::writer::write_geometry(&mut buf, &line_string, Default::default()).unwrap();
+///
+/// let bounds = read_wkb_bounds(&buf).unwrap().unwrap();
+/// assert_eq!(bounds.min().x_y(), (1., 2.));
+/// assert_eq!(bounds.max().x_y(), (3., 5.));
+/// ```
+pub fn read_wkb_bounds(buf: &[u8]) -> WKBResult<Option<WKBBounds>> {
+    WKBBounds::try_new(buf)
+}
+
+/// Summarize a WKB or EWKB geometry in a single pass over its buffer.
+///
+/// The returned [`WkbInfo`] has the type, dimension, byte order, flavor, SRID, part count, ring
+/// count, coordinate count and size of the geometry. This does not build the reader structure of
+/// the geometry and never allocates.
+///
+/// Returns [`WKBError::CurveGeometry`] for curve types and [`WKBError::TooDeeplyNested`] for
+/// geometries nested more than [`MAX_NESTING_DEPTH`] levels deep, like [`read_wkb`].
+///
+/// ```
+/// use wkb::reader::read_wkb_info;
+/// use wkb::{WKBDimension, WKBFlavor, WKBType};
+///
+/// let polygon = geo_types::Polygon::new(
+///     geo_types::LineString::from(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)]),
+///     vec![],
+/// );
+/// let multi_polygon = geo_types::MultiPolygon::new(vec![polygon.clone(), polygon]);
+/// let options = wkb::writer::WriteOptions {
+///     flavor: WKBFlavor::Extended { srid: Some(4326) },
+///     ..Default::default()
+/// };
+/// let mut buf = Vec::new();
+/// wkb::writer::write_geometry(&mut buf, &multi_polygon, options).unwrap();
+///
+/// let info = read_wkb_info(&buf).unwrap();
+/// assert_eq!(info.wkb_type(), WKBType::MultiPolygon(WKBDimension::Xy));
+/// assert_eq!(info.srid(), Some(4326));
+/// assert_eq!(info.num_parts(), Some(2));
+/// assert_eq!(info.num_rings(), 2);
+/// assert_eq!(info.num_coords(), 8);
+/// assert_eq!(info.size(), buf.len() as u64);
+/// ```
+pub fn read_wkb_info(buf: &[u8]) -> WKBResult<WkbInfo> {
+    WkbInfo::try_new(buf)
+}
+
+/// Parse a WKB byte slice into a geometry that may be one of the SQL/MM curve types.
+///
+/// [`read_wkb`] returns [`WKBError::CurveGeometry`] for a CircularString, CompoundCurve,
+/// CurvePolygon, MultiCurve or MultiSurface, because [`geo_traits`] cannot represent them. This
+/// returns a [`CurvedWkb`], which holds either a curve geometry, a GeometryCollection containing
+/// curves or a linear [`Wkb`].
+pub fn read_curved_wkb(buf: &[u8]) -> WKBResult<CurvedWkb<'_>> {
+    CurvedWkb::try_new(buf)
+}
+
+/// Parse a WKB byte slice into a geometry, erroring if any bytes remain after the geometry.
+///
+/// This is the same as [`read_wkb`] except that a buffer with trailing bytes after the end of the
+/// geometry returns [`WKBError::TrailingBytes`] instead of being accepted.
+pub fn read_wkb_strict(buf: &[u8]) -> WKBResult<Wkb<'_>> {
+    let (geometry, consumed) = read_wkb_prefix(buf)?;
+    if consumed < buf.len() {
+        return Err(WKBError::TrailingBytes {
+            offset: consumed as u64,
+            trailing: (buf.len() - consumed) as u64,
+        });
+    }
+    Ok(geometry)
+}
+
+/// Parse the WKB geometry at the start of a byte slice, returning the geometry and the number of
+/// bytes it consumed.
+///
+/// Any bytes after the end of the geometry are ignored. This can be used to parse geometries that
+/// are packed back-to-back in a single buffer:
+///
+/// ```
+/// use wkb::reader::read_wkb_prefix;
+///
+/// # let point = geo_types::Point::new(1., 2.);
+/// # let mut buf = Vec::new();
+/// # wkb::writer::write_geometry(&mut buf, &point, Default::default()).unwrap();
+/// # wkb::writer::write_geometry(&mut buf, &point, Default::default()).unwrap();
+/// let mut remaining = buf.as_slice();
+/// let mut geometries = Vec::new();
+/// while !remaining.is_empty() {
+///     let (geometry, consumed) = read_wkb_prefix(remaining).unwrap();
+///     geometries.push(geometry);
+///     remaining = &remaining[consumed..];
+/// }
+/// assert_eq!(geometries.len(), 2);
+/// ```
+pub fn read_wkb_prefix(buf: &[u8]) -> WKBResult<(Wkb<'_>, usize)> {
+    let geometry = Wkb::try_new(buf)?;
+    let consumed = geometry.size() as usize;
+    Ok((geometry, consumed))
+}
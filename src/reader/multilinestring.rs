@@ -1,8 +1,8 @@
-use std::io::Cursor;
-
-use crate::common::WKBDimension;
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::flat_coords::FlatCoords;
 use crate::reader::linestring::LineString;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{check_bounds, read_child_header, read_srid, read_u32_at};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::MultiLineStringTrait;
@@ -17,41 +17,50 @@ pub struct MultiLineString<'a> {
     /// A LineString object for each of the internal line strings
     wkb_line_strings: Vec<LineString<'a>>,
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> MultiLineString<'a> {
-    pub(crate) fn new(buf: &'a [u8], byte_order: Endianness, dim: WKBDimension) -> Self {
-        let mut offset = 0;
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        mut offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        if srid.is_some() {
             offset += 4;
         }
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES + offset);
-        let num_line_strings = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+        let num_line_strings = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
 
+        // - existing offset into buffer
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numLineStrings
-        let mut line_string_offset = 1 + 4 + 4;
-        if has_srid {
-            line_string_offset += 4;
-        }
+        let mut line_string_offset = offset + 1 + 4 + 4;
+
+        // Each LineString takes at least 9 bytes for its header, so check the count against the
+        // buffer before allocating.
+        check_bounds(buf, line_string_offset, 9 * num_line_strings as u64)?;
 
         let mut wkb_line_strings = Vec::with_capacity(num_line_strings);
-        for _ in 0..num_line_strings {
-            let ls = LineString::new(buf, byte_order, line_string_offset, dim);
+        for i in 0..num_line_strings {
+            // Each LineString has its own byte order, which may differ from the MultiLineString's
+            let ls = read_child_header(buf, line_string_offset, WKBType::LineString(dim))
+                .and_then(|ls_byte_order| {
+                    LineString::try_new(buf, ls_byte_order, line_string_offset, dim)
+                })
+                .map_err(|err| err.in_part(i))?;
             wkb_line_strings.push(ls);
             line_string_offset += ls.size();
         }
 
-        Self {
+        Ok(Self {
             wkb_line_strings,
             dim,
-            has_srid,
-        }
+            srid,
+        })
     }
 
     /// The number of bytes in this object, including any header
@@ -63,7 +72,7 @@ impl<'a> MultiLineString<'a> {
         // - 4: numPoints
         // - Point::size() * self.num_points: the size of each Point for each point
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
         self.wkb_line_strings
@@ -71,9 +80,22 @@ impl<'a> MultiLineString<'a> {
             .fold(header, |acc, ls| acc + ls.size())
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The coordinates of each LineString of this MultiLineString in turn, for decoding them at
+    /// once
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        let runs = self.wkb_line_strings.iter().map(|ls| ls.coord_run());
+        FlatCoords::new(runs.collect(), self.dim)
+    }
 }
 
 impl<'a> MultiLineStringTrait for MultiLineString<'a> {
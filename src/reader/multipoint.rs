@@ -1,8 +1,8 @@
-use std::io::Cursor;
-
-use crate::common::WKBDimension;
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::flat_coords::{CoordRun, FlatCoords};
 use crate::reader::point::Point;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{read_child_header, read_srid, read_u32_at, validated_byte_order};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::MultiPointTrait;
@@ -13,34 +13,61 @@ use geo_traits::MultiPointTrait;
 #[derive(Debug, Clone, Copy)]
 pub struct MultiPoint<'a> {
     buf: &'a [u8],
-    byte_order: Endianness,
+
+    /// The offset into the buffer where this MultiPoint is located
+    offset: u64,
 
     /// The number of points in this multi point
     num_points: usize,
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> MultiPoint<'a> {
-    pub(crate) fn new(buf: &'a [u8], byte_order: Endianness, dim: WKBDimension) -> Self {
-        let mut offset = 0;
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
-            offset += 4;
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+
+        // Set reader to after 1-byte byteOrder, 4-byte wkbType and any SRID
+        let mut num_points_offset = offset + 1 + 4;
+        if srid.is_some() {
+            num_points_offset += 4;
         }
+        let num_points = read_u32_at(buf, byte_order, num_points_offset)? as usize;
 
-        let mut reader = Cursor::new(buf);
-        // Set reader to after 1-byte byteOrder and 4-byte wkbType
-        reader.set_position(1 + 4 + offset);
-        let num_points = reader.read_u32(byte_order).unwrap().try_into().unwrap();
-
-        Self {
+        let multi_point = Self {
             buf,
-            byte_order,
+            offset,
             num_points,
             dim,
-            has_srid,
+            srid,
+        };
+
+        // Points are accessed by a fixed stride, so validate up front that every point is
+        // contained in the buffer and has the expected size, without an SRID of its own.
+        for i in 0..num_points {
+            let point_offset = multi_point.point_offset(i as u64);
+            // Each point has its own byte order, which may differ from the MultiPoint's
+            read_child_header(buf, point_offset, WKBType::Point(dim))
+                .and_then(|point_byte_order| {
+                    Point::try_new(buf, point_byte_order, point_offset, dim)
+                })
+                .and_then(|point| match point.srid() {
+                    Some(srid) => Err(WKBError::UnexpectedSrid {
+                        srid,
+                        offset: point_offset,
+                        path: vec![],
+                    }),
+                    None => Ok(()),
+                })
+                .map_err(|err| err.in_part(i))?;
         }
+
+        Ok(multi_point)
     }
 
     /// The number of bytes in this object, including any header
@@ -52,7 +79,7 @@ impl<'a> MultiPoint<'a> {
         // - 4: numPoints
         // - Point::size() * self.num_points: the size of each Point for each point
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
         header + ((1 + 4 + (self.dim.size() as u64 * 8)) * self.num_points as u64)
@@ -64,15 +91,34 @@ impl<'a> MultiPoint<'a> {
         // - 4: wkbType
         // - 4: numPoints
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
-        header + ((1 + 4 + (self.dim.size() as u64 * 8)) * i)
+        self.offset + header + ((1 + 4 + (self.dim.size() as u64 * 8)) * i)
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The coordinates of each Point of this MultiPoint in turn, for decoding them at once
+    ///
+    /// Empty Points are included, with NaN values.
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        let runs = (0..self.num_points).map(|i| {
+            let offset = self.point_offset(i as u64);
+            // Each point has its own byte order, and its coordinate follows its type
+            let byte_order = validated_byte_order(self.buf, offset);
+            CoordRun::new(self.buf, byte_order, offset + 5, 1, self.dim)
+        });
+        FlatCoords::new(runs.collect(), self.dim)
+    }
 }
 
 impl<'a> MultiPointTrait for MultiPoint<'a> {
@@ -91,11 +137,13 @@ impl<'a> MultiPointTrait for MultiPoint<'a> {
     }
 
     unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        let offset = self.point_offset(i as u64);
         Point::new(
             self.buf,
-            self.byte_order,
-            self.point_offset(i.try_into().unwrap()),
+            validated_byte_order(self.buf, offset),
+            offset,
             self.dim,
+            None,
         )
     }
 }
@@ -116,11 +164,13 @@ impl<'a> MultiPointTrait for &'a MultiPoint<'a> {
     }
 
     unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        let offset = self.point_offset(i as u64);
         Point::new(
             self.buf,
-            self.byte_order,
-            self.point_offset(i.try_into().unwrap()),
+            validated_byte_order(self.buf, offset),
+            offset,
             self.dim,
+            None,
         )
     }
 }
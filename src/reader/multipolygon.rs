@@ -1,8 +1,9 @@
-use std::io::Cursor;
-
-use crate::common::WKBDimension;
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::flat_coords::FlatCoords;
 use crate::reader::polygon::Polygon;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::triangle::Triangle;
+use crate::reader::util::{check_bounds, read_child_header, read_srid, read_u32_at};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::MultiPolygonTrait;
@@ -17,41 +18,68 @@ pub struct MultiPolygon<'a> {
     wkb_polygons: Vec<Polygon<'a>>,
 
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> MultiPolygon<'a> {
-    pub(crate) fn new(buf: &'a [u8], byte_order: Endianness, dim: WKBDimension) -> Self {
-        let mut offset = 0;
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        Self::try_new_with_parts(buf, byte_order, offset, dim, WKBType::Polygon(dim))
+    }
+
+    /// Parse a geometry with the layout of a MultiPolygon, whose parts have type `part_type`.
+    ///
+    /// This is shared with PolyhedralSurface, whose parts are Polygons, and TIN, whose parts are
+    /// Triangles.
+    pub(crate) fn try_new_with_parts(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        mut offset: u64,
+        dim: WKBDimension,
+        part_type: WKBType,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        if srid.is_some() {
             offset += 4;
         }
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES + offset);
-        let num_polygons = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+        let num_polygons = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
 
+        // - existing offset into buffer
         // - 1: byteOrder
         // - 4: wkbType
-        // - 4: numLineStrings
-        let mut polygon_offset = 1 + 4 + 4;
-        if has_srid {
-            polygon_offset += 4;
-        }
+        // - 4: numPolygons
+        let mut polygon_offset = offset + 1 + 4 + 4;
+
+        // Each Polygon takes at least 9 bytes for its header, so check the count against the
+        // buffer before allocating.
+        check_bounds(buf, polygon_offset, 9 * num_polygons as u64)?;
 
         let mut wkb_polygons = Vec::with_capacity(num_polygons);
-        for _ in 0..num_polygons {
-            let polygon = Polygon::new(buf, byte_order, polygon_offset, dim);
+        for i in 0..num_polygons {
+            // Each Polygon has its own byte order, which may differ from the MultiPolygon's
+            let polygon = read_child_header(buf, polygon_offset, part_type)
+                .and_then(|polygon_byte_order| match part_type {
+                    WKBType::Triangle(_) => {
+                        Triangle::try_new(buf, polygon_byte_order, polygon_offset, dim)
+                            .map(Triangle::into_polygon)
+                    }
+                    _ => Polygon::try_new(buf, polygon_byte_order, polygon_offset, dim),
+                })
+                .map_err(|err| err.in_part(i))?;
             polygon_offset += polygon.size();
             wkb_polygons.push(polygon);
         }
 
-        Self {
+        Ok(Self {
             wkb_polygons,
             dim,
-            has_srid,
-        }
+            srid,
+        })
     }
 
     /// The number of bytes in this object, including any header
@@ -62,7 +90,7 @@ impl<'a> MultiPolygon<'a> {
         // - 4: wkbType
         // - 4: numPolygons
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
         self.wkb_polygons
@@ -70,9 +98,25 @@ impl<'a> MultiPolygon<'a> {
             .fold(header, |acc, x| acc + x.size())
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The coordinates of each ring of each Polygon of this MultiPolygon in turn, for decoding
+    /// them at once
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        let runs = self
+            .wkb_polygons
+            .iter()
+            .flat_map(|polygon| polygon.coord_runs());
+        FlatCoords::new(runs.collect(), self.dim)
+    }
 }
 
 impl<'a> MultiPolygonTrait for MultiPolygon<'a> {
@@ -0,0 +1,67 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::curve::{read_parts, Surface};
+use crate::Endianness;
+
+/// A WKB MultiSurface
+///
+/// A MultiSurface is a collection of Polygons and CurvePolygons.
+#[derive(Debug, Clone)]
+pub struct MultiSurface<'a> {
+    /// The surfaces in this collection
+    surfaces: Vec<Surface<'a>>,
+    dim: WKBDimension,
+    srid: Option<i32>,
+}
+
+impl<'a> MultiSurface<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let (surfaces, srid) = read_parts(
+            buf,
+            byte_order,
+            offset,
+            WKBType::MultiSurface(dim),
+            Surface::try_new_at,
+            Surface::size,
+        )?;
+        Ok(Self {
+            surfaces,
+            dim,
+            srid,
+        })
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numSurfaces
+        let mut header = 1 + 4 + 4;
+        if self.srid.is_some() {
+            header += 4;
+        }
+        self.surfaces.iter().fold(header, |acc, x| acc + x.size())
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.dim
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The surfaces in this collection
+    pub fn surfaces(&self) -> &[Surface<'a>] {
+        &self.surfaces
+    }
+}
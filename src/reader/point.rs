@@ -1,6 +1,7 @@
 use crate::common::WKBDimension;
+use crate::error::WKBResult;
 use crate::reader::coord::Coord;
-use crate::reader::util::has_srid;
+use crate::reader::util::{check_bounds, read_srid};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::{CoordTrait, PointTrait};
@@ -16,21 +17,35 @@ pub struct Point<'a> {
     coord: Coord<'a>,
     dim: WKBDimension,
     is_empty: bool,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> Point<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        check_bounds(buf, offset, point_size(dim, srid.is_some()))?;
+        Ok(Self::new(buf, byte_order, offset, dim, srid))
+    }
+
+    /// Construct a Point without validating it against the buffer.
+    ///
+    /// Callers must have already checked that the full point lies within `buf`, e.g. through
+    /// [`Point::try_new`].
     pub(crate) fn new(
         buf: &'a [u8],
         byte_order: Endianness,
         offset: u64,
         dim: WKBDimension,
+        srid: Option<i32>,
     ) -> Self {
-        let has_srid = has_srid(buf, byte_order, offset);
-
         // The space of the byte order + geometry type
         let mut offset = offset + 5;
-        if has_srid {
+        if srid.is_some() {
             // Skip SRID bytes if they exist
             offset += 4;
         }
@@ -49,7 +64,7 @@ impl<'a> Point<'a> {
             coord,
             dim,
             is_empty,
-            has_srid,
+            srid,
         }
     }
 
@@ -57,19 +72,30 @@ impl<'a> Point<'a> {
     ///
     /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
-        // - 1: byteOrder
-        // - 4: wkbType
-        // - dim size * 8: two f64s
-        let mut header = 1 + 4;
-        if self.has_srid {
-            header += 4;
-        }
-        header + (self.dim.size() as u64 * 8)
+        point_size(self.dim, self.srid.is_some())
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+}
+
+/// The number of bytes in a Point of the given dimension, including any header
+pub(crate) fn point_size(dim: WKBDimension, has_srid: bool) -> u64 {
+    // - 1: byteOrder
+    // - 4: wkbType
+    // - dim size * 8: two f64s
+    let mut header = 1 + 4;
+    if has_srid {
+        header += 4;
+    }
+    header + (dim.size() as u64 * 8)
 }
 
 impl<'a> PointTrait for Point<'a> {
@@ -1,8 +1,8 @@
-use std::io::Cursor;
-
 use crate::common::WKBDimension;
+use crate::error::WKBResult;
+use crate::reader::flat_coords::{CoordRun, FlatCoords};
 use crate::reader::linearring::WKBLinearRing;
-use crate::reader::util::{has_srid, ReadBytesExt};
+use crate::reader::util::{check_bounds, read_srid, read_u32_at};
 use crate::Endianness;
 use geo_traits::Dimensions;
 use geo_traits::PolygonTrait;
@@ -17,38 +17,46 @@ const HEADER_BYTES: u64 = 5;
 pub struct Polygon<'a> {
     wkb_linear_rings: Vec<WKBLinearRing<'a>>,
     dim: WKBDimension,
-    has_srid: bool,
+    srid: Option<i32>,
 }
 
 impl<'a> Polygon<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, mut offset: u64, dim: WKBDimension) -> Self {
-        let has_srid = has_srid(buf, byte_order, offset);
-        if has_srid {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        mut offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let srid = read_srid(buf, byte_order, offset)?;
+        if srid.is_some() {
             offset += 4;
         }
 
-        let mut reader = Cursor::new(buf);
-        reader.set_position(HEADER_BYTES + offset);
-
-        let num_rings = reader.read_u32(byte_order).unwrap().try_into().unwrap();
+        let num_rings = read_u32_at(buf, byte_order, HEADER_BYTES + offset)? as usize;
 
         // - existing offset into buffer
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numLineStrings
         let mut ring_offset = offset + 1 + 4 + 4;
+
+        // Each ring takes at least 4 bytes for its point count, so check the ring count against
+        // the buffer before allocating.
+        check_bounds(buf, ring_offset, 4 * num_rings as u64)?;
+
         let mut wkb_linear_rings = Vec::with_capacity(num_rings);
-        for _ in 0..num_rings {
-            let polygon = WKBLinearRing::new(buf, byte_order, ring_offset, dim);
-            wkb_linear_rings.push(polygon);
-            ring_offset += polygon.size();
+        for i in 0..num_rings {
+            let ring = WKBLinearRing::try_new(buf, byte_order, ring_offset, dim)
+                .map_err(|err| err.in_part(i))?;
+            wkb_linear_rings.push(ring);
+            ring_offset += ring.size();
         }
 
-        Self {
+        Ok(Self {
             wkb_linear_rings,
             dim,
-            has_srid,
-        }
+            srid,
+        })
     }
 
     /// The number of bytes in this object, including any header
@@ -60,7 +68,7 @@ impl<'a> Polygon<'a> {
         // - 4: numPoints
         // - size of each linear ring
         let mut header = 1 + 4 + 4;
-        if self.has_srid {
+        if self.srid.is_some() {
             header += 4;
         }
 
@@ -69,9 +77,24 @@ impl<'a> Polygon<'a> {
             .fold(header, |acc, ring| acc + ring.size())
     }
 
+    /// The dimension of this geometry
     pub fn dimension(&self) -> WKBDimension {
         self.dim
     }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The coordinates of each ring of this Polygon in turn, for decoding them at once
+    pub fn flat_coords(&self) -> FlatCoords<'a> {
+        FlatCoords::new(self.coord_runs().collect(), self.dim)
+    }
+
+    pub(crate) fn coord_runs(&self) -> impl Iterator<Item = CoordRun<'a>> + '_ {
+        self.wkb_linear_rings.iter().map(|ring| ring.coord_run())
+    }
 }
 
 impl<'a> PolygonTrait for Polygon<'a> {
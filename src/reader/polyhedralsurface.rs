@@ -0,0 +1,90 @@
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::multipolygon::MultiPolygon;
+use crate::reader::polygon::Polygon;
+use crate::Endianness;
+use geo_traits::Dimensions;
+use geo_traits::MultiPolygonTrait;
+
+/// A WKB PolyhedralSurface
+///
+/// A PolyhedralSurface is a collection of Polygon patches that share boundaries, such as the faces
+/// of a building.
+///
+/// This has the same layout as a MultiPolygon, and is exposed through [`MultiPolygonTrait`].
+#[derive(Debug, Clone)]
+pub struct PolyhedralSurface<'a>(MultiPolygon<'a>);
+
+impl<'a> PolyhedralSurface<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        MultiPolygon::try_new_with_parts(buf, byte_order, offset, dim, WKBType::Polygon(dim))
+            .map(Self)
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        self.0.size()
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.0.dimension()
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.0.srid()
+    }
+
+    /// View this PolyhedralSurface as a MultiPolygon of its polygon patches
+    pub fn as_multi_polygon(&self) -> &MultiPolygon<'a> {
+        &self.0
+    }
+}
+
+impl<'a> MultiPolygonTrait for PolyhedralSurface<'a> {
+    type T = f64;
+    type PolygonType<'b>
+        = Polygon<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.0.dim()
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.num_polygons()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        self.0.polygon_unchecked(i)
+    }
+}
+
+impl<'a> MultiPolygonTrait for &'a PolyhedralSurface<'a> {
+    type T = f64;
+    type PolygonType<'b>
+        = Polygon<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.0.dim()
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.num_polygons()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        self.0.polygon_unchecked(i)
+    }
+}
@@ -0,0 +1,189 @@
+use std::io::Read;
+
+use crate::error::{WKBError, WKBResult};
+use crate::reader::lazy::LazyWkb;
+use crate::reader::util::read_u32_at;
+use crate::reader::{read_wkb_strict, Wkb};
+use crate::Endianness;
+
+/// The minimum number of bytes to request from the underlying reader at once
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// How consecutive geometries are delimited in a stream of WKB.
+#[derive(Debug, Clone, Copy)]
+pub enum WKBFraming {
+    /// Each geometry is preceded by its length in bytes, as a `u32` with the given byte order.
+    LengthPrefixed(Endianness),
+    /// Geometries are packed back-to-back, and the end of each geometry is found from its
+    /// contents.
+    SelfDelimited,
+}
+
+/// A reader for a sequence of WKB geometries from any [`Read`].
+///
+/// Geometries are read one at a time into a single internal buffer, which is reused for every
+/// geometry. Because each geometry borrows from that buffer, this does not implement
+/// [`Iterator`]; instead call [`next_geometry`][Self::next_geometry] in a loop:
+///
+/// ```
+/// use geo_traits::GeometryTrait;
+/// use wkb::reader::{WKBFraming, WKBStreamReader};
+///
+/// # let point = geo_types::Point::new(1., 2.);
+/// # let mut buf = Vec::new();
+/// # wkb::writer::write_geometry(&mut buf, &point, Default::default()).unwrap();
+/// # wkb::writer::write_geometry(&mut buf, &point, Default::default()).unwrap();
+/// let mut reader = WKBStreamReader::new(buf.as_slice(), WKBFraming::SelfDelimited);
+/// let mut count = 0;
+/// while let Some(geometry) = reader.next_geometry().unwrap() {
+///     assert_eq!(geometry.dim(), geo_traits::Dimensions::Xy);
+///     count += 1;
+/// }
+/// assert_eq!(count, 2);
+/// ```
+#[derive(Debug)]
+pub struct WKBStreamReader<R: Read> {
+    reader: R,
+    framing: WKBFraming,
+
+    /// Bytes read from `reader`, of which those from `start` on have not yet been returned as
+    /// part of a geometry
+    buf: Vec<u8>,
+
+    /// The offset into `buf` of the first byte not yet returned. Earlier bytes are only removed
+    /// when more of the stream is read, so that returning a geometry never moves the bytes
+    /// after it.
+    start: usize,
+
+    /// The number of bytes from `start` that belong to the previous geometry
+    consumed: usize,
+}
+
+impl<R: Read> WKBStreamReader<R> {
+    /// Create a new reader over a stream of geometries with the given framing
+    pub fn new(reader: R, framing: WKBFraming) -> Self {
+        Self {
+            reader,
+            framing,
+            buf: Vec::new(),
+            start: 0,
+            consumed: 0,
+        }
+    }
+
+    /// Read the next geometry from the stream.
+    ///
+    /// Returns `Ok(None)` when the stream ends cleanly between geometries.
+    pub fn next_geometry(&mut self) -> WKBResult<Option<Wkb<'_>>> {
+        self.start += self.consumed;
+        self.consumed = 0;
+
+        if !self.fill(1)? {
+            return Ok(None);
+        }
+
+        match self.framing {
+            WKBFraming::LengthPrefixed(byte_order) => {
+                let len = self.length_prefixed_frame(byte_order)?;
+                self.consumed = 4 + len;
+                let start = self.start + 4;
+                read_wkb_strict(&self.buf[start..start + len]).map(Some)
+            }
+            WKBFraming::SelfDelimited => {
+                let len = self.self_delimited_frame()?;
+                self.consumed = len;
+                Wkb::try_new(&self.buf[self.start..self.start + len]).map(Some)
+            }
+        }
+    }
+
+    /// Consume this reader, returning the underlying reader.
+    ///
+    /// Any bytes that have been read ahead into the internal buffer are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the length prefix of the next geometry and the geometry after it, returning the
+    /// length
+    fn length_prefixed_frame(&mut self, byte_order: Endianness) -> WKBResult<usize> {
+        self.fill_exact(4)?;
+        let len = read_u32_at(self.unread(), byte_order, 0)? as usize;
+        self.fill_exact(4 + len)?;
+        Ok(len)
+    }
+
+    /// Find the length of the next geometry, reading more of the stream whenever the buffer ends
+    /// too early.
+    ///
+    /// The length is found by walking the structure of the geometry with [`LazyWkb::size`],
+    /// which neither allocates nor decodes coordinates, so that the geometry is only parsed once
+    /// it is known to be complete.
+    fn self_delimited_frame(&mut self) -> WKBResult<usize> {
+        loop {
+            let unread = self.unread();
+            let min_len = match LazyWkb::try_new_at(unread, 0, 0).and_then(|g| g.size()) {
+                Ok(size) => return Ok(size as usize),
+                Err(WKBError::UnexpectedEof { offset, needed, .. })
+                    if offset + needed > unread.len() as u64 =>
+                {
+                    offset + needed
+                }
+                Err(err) => return Err(err),
+            };
+            self.fill_exact(min_len.try_into().unwrap_or(usize::MAX))?;
+        }
+    }
+
+    /// The bytes read from the stream that have not yet been returned
+    fn unread(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+
+    /// Like [`fill`][Self::fill], but errors if the stream ends first
+    fn fill_exact(&mut self, min_len: usize) -> WKBResult<()> {
+        if self.fill(min_len)? {
+            Ok(())
+        } else {
+            let available = self.unread().len();
+            Err(WKBError::UnexpectedEof {
+                offset: available as u64,
+                needed: (min_len - available) as u64,
+                path: vec![],
+            })
+        }
+    }
+
+    /// Read from the stream until at least `min_len` bytes are unread.
+    ///
+    /// Returns `false` if the stream ended first. The bytes that were already returned are
+    /// dropped before reading. The buffer grows by at most its current size for each read, so a
+    /// corrupt length cannot cause a huge allocation.
+    fn fill(&mut self, min_len: usize) -> WKBResult<bool> {
+        if self.unread().len() >= min_len {
+            return Ok(true);
+        }
+        self.buf.drain(..self.start);
+        self.start = 0;
+        while self.buf.len() < min_len {
+            let len = self.buf.len();
+            let chunk = (min_len - len).clamp(CHUNK_SIZE, len.max(CHUNK_SIZE));
+            self.buf.resize(len + chunk, 0);
+            let read = loop {
+                match self.reader.read(&mut self.buf[len..]) {
+                    Ok(read) => break read,
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(err) => {
+                        self.buf.truncate(len);
+                        return Err(err.into());
+                    }
+                }
+            };
+            self.buf.truncate(len + read);
+            if read == 0 {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::common::WKBDimension;
+use crate::error::{WKBError, WKBResult};
+use crate::reader::coord::Coord;
+use crate::reader::polygon::Polygon;
+use crate::Endianness;
+use geo_traits::{CoordTrait, Dimensions, LineStringTrait, PolygonTrait, TriangleTrait};
+
+/// A WKB Triangle
+///
+/// A Triangle has the same layout as a Polygon with a single closed ring of four points. It is
+/// either empty or has exactly one such ring.
+///
+/// This has been preprocessed, so access to any internal coordinate is `O(1)`.
+#[derive(Debug, Clone)]
+pub struct Triangle<'a>(Polygon<'a>);
+
+impl<'a> Triangle<'a> {
+    pub(crate) fn try_new(
+        buf: &'a [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<Self> {
+        let polygon = Polygon::try_new(buf, byte_order, offset, dim)?;
+        let num_rings = polygon
+            .exterior()
+            .map_or(0, |_| 1 + polygon.num_interiors());
+        let num_points = polygon.exterior().map_or(0, |ring| ring.num_coords());
+        let closed = polygon.exterior().is_none_or(|ring| {
+            match (ring.coord(0), ring.coord(num_points.saturating_sub(1))) {
+                (Some(first), Some(last)) => {
+                    (0..dim.size()).all(|n| first.nth_or_panic(n) == last.nth_or_panic(n))
+                }
+                _ => true,
+            }
+        });
+        if num_rings == 0 || (num_rings == 1 && num_points == 4 && closed) {
+            Ok(Self(polygon))
+        } else {
+            Err(WKBError::InvalidTriangle {
+                num_rings,
+                num_points,
+                closed,
+                offset,
+                path: vec![],
+            })
+        }
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        self.0.size()
+    }
+
+    /// The dimension of this geometry
+    pub fn dimension(&self) -> WKBDimension {
+        self.0.dimension()
+    }
+
+    /// The SRID embedded in this geometry, if it was encoded as EWKB with an SRID
+    pub fn srid(&self) -> Option<i32> {
+        self.0.srid()
+    }
+
+    /// Whether this Triangle has no ring
+    pub fn is_empty(&self) -> bool {
+        self.0.exterior().is_none()
+    }
+
+    /// View this Triangle as a Polygon with a single ring
+    pub fn as_polygon(&self) -> &Polygon<'a> {
+        &self.0
+    }
+
+    pub(crate) fn into_polygon(self) -> Polygon<'a> {
+        self.0
+    }
+
+    /// The coordinate at index `i` of the ring.
+    ///
+    /// Panics if this Triangle is empty.
+    fn coord(&self, i: usize) -> Coord<'a> {
+        let ring = self.0.exterior().expect("Triangle is empty");
+        // Safety: a non-empty Triangle was validated to have four points
+        unsafe { ring.coord_unchecked(i) }
+    }
+}
+
+/// Access to the corners of a non-empty Triangle.
+///
+/// These methods panic if the Triangle [is empty][Triangle::is_empty].
+/// [`Wkb`][crate::reader::Wkb] exposes an empty Triangle as an empty Polygon instead.
+impl<'a> TriangleTrait for Triangle<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dimension().into()
+    }
+
+    fn first(&self) -> Self::CoordType<'_> {
+        self.coord(0)
+    }
+
+    fn second(&self) -> Self::CoordType<'_> {
+        self.coord(1)
+    }
+
+    fn third(&self) -> Self::CoordType<'_> {
+        self.coord(2)
+    }
+}
+
+impl<'a> TriangleTrait for &'a Triangle<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = Coord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dimension().into()
+    }
+
+    fn first(&self) -> Self::CoordType<'_> {
+        self.coord(0)
+    }
+
+    fn second(&self) -> Self::CoordType<'_> {
+        self.coord(1)
+    }
+
+    fn third(&self) -> Self::CoordType<'_> {
+        self.coord(2)
+    }
+}
@@ -1,37 +1,131 @@
-use byteorder::{BigEndian, LittleEndian};
+use std::mem::discriminant;
 
-use crate::common::WKBGeometryCode;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::{WKBGeometryCode, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::MAX_NESTING_DEPTH;
 use crate::Endianness;
-use std::io::{Cursor, Error};
-
-pub(crate) trait ReadBytesExt: byteorder::ReadBytesExt {
-    fn read_u32(&mut self, byte_order: Endianness) -> Result<u32, Error> {
-        match byte_order {
-            Endianness::BigEndian => byteorder::ReadBytesExt::read_u32::<BigEndian>(self),
-            Endianness::LittleEndian => byteorder::ReadBytesExt::read_u32::<LittleEndian>(self),
-        }
+
+/// Ensure that `len` bytes starting at `offset` are within the buffer
+pub(crate) fn check_bounds(buf: &[u8], offset: u64, len: u64) -> WKBResult<()> {
+    let end = offset.checked_add(len);
+    if end.is_some_and(|end| end <= buf.len() as u64) {
+        Ok(())
+    } else {
+        Err(WKBError::UnexpectedEof {
+            offset,
+            needed: len,
+            path: vec![],
+        })
     }
+}
 
-    fn read_f64(&mut self, byte_order: Endianness) -> Result<f64, Error> {
-        match byte_order {
-            Endianness::BigEndian => byteorder::ReadBytesExt::read_f64::<BigEndian>(self),
-            Endianness::LittleEndian => byteorder::ReadBytesExt::read_f64::<LittleEndian>(self),
-        }
+/// Check that a geometry starting at `offset`, nested `depth` levels deep, is within
+/// [`MAX_NESTING_DEPTH`]
+pub(crate) fn check_depth(depth: usize, offset: u64) -> WKBResult<()> {
+    if depth <= MAX_NESTING_DEPTH {
+        Ok(())
+    } else {
+        Err(WKBError::TooDeeplyNested {
+            offset,
+            path: vec![],
+        })
     }
 }
 
-/// All types that implement `Read` get methods defined in `ReadBytesExt`
-/// for free.
-impl<R: std::io::Read + ?Sized> ReadBytesExt for R {}
+/// Read a single byte at `offset`, checking that it is within the buffer
+pub(crate) fn read_u8_at(buf: &[u8], offset: u64) -> WKBResult<u8> {
+    check_bounds(buf, offset, 1)?;
+    Ok(buf[offset as usize])
+}
+
+/// Read a `u32` at `offset`, checking that it is within the buffer
+pub(crate) fn read_u32_at(buf: &[u8], byte_order: Endianness, offset: u64) -> WKBResult<u32> {
+    check_bounds(buf, offset, 4)?;
+    let bytes = &buf[offset as usize..];
+    let value = match byte_order {
+        Endianness::BigEndian => BigEndian::read_u32(bytes),
+        Endianness::LittleEndian => LittleEndian::read_u32(bytes),
+    };
+    Ok(value)
+}
 
-/// Return `true` if this WKB item is EWKB and has an embedded SRID
-pub(crate) fn has_srid(buf: &[u8], byte_order: Endianness, offset: u64) -> bool {
-    // Read geometry code to see if an SRID exists.
-    let mut reader = Cursor::new(buf);
+/// Read the byte order byte at `offset`, checking that it is a valid value
+pub(crate) fn read_byte_order(buf: &[u8], offset: u64) -> WKBResult<Endianness> {
+    let byte = read_u8_at(buf, offset)?;
+    Endianness::try_from(byte).map_err(|_| WKBError::InvalidByteOrder {
+        byte,
+        offset,
+        path: vec![],
+    })
+}
 
-    // Skip 1-byte byte order that we already know
-    reader.set_position(1 + offset);
+/// Read the byte order byte at `offset` of a geometry whose header was already validated
+pub(crate) fn validated_byte_order(buf: &[u8], offset: u64) -> Endianness {
+    match buf[offset as usize] {
+        0 => Endianness::BigEndian,
+        _ => Endianness::LittleEndian,
+    }
+}
 
-    let geometry_code = WKBGeometryCode::new(reader.read_u32(byte_order).unwrap());
-    geometry_code.has_srid()
+/// Read the byte order and geometry type of the geometry starting at `offset`
+pub(crate) fn read_header(buf: &[u8], offset: u64) -> WKBResult<(Endianness, WKBType)> {
+    let byte_order = read_byte_order(buf, offset)?;
+    let geometry_code = WKBGeometryCode::new(read_u32_at(buf, byte_order, offset + 1)?);
+    let wkb_type = geometry_code
+        .get_type()
+        .map_err(|err| err.at_offset(offset))?;
+    Ok((byte_order, wkb_type))
+}
+
+/// Check that a nested geometry has the same dimension as its parent
+pub(crate) fn check_child_dimension(
+    parent: &WKBType,
+    child: &WKBType,
+    offset: u64,
+) -> WKBResult<()> {
+    if parent.dimension() == child.dimension() {
+        Ok(())
+    } else {
+        Err(WKBError::DimensionMismatch {
+            parent: parent.dimension(),
+            child: child.dimension(),
+            offset,
+            path: vec![],
+        })
+    }
+}
+
+/// Read the header of a nested geometry starting at `offset` and check that it matches the
+/// `expected` type and dimension.
+pub(crate) fn read_child_header(
+    buf: &[u8],
+    offset: u64,
+    expected: WKBType,
+) -> WKBResult<Endianness> {
+    let (byte_order, wkb_type) = read_header(buf, offset)?;
+    check_child_dimension(&expected, &wkb_type, offset)?;
+    if discriminant(&expected) != discriminant(&wkb_type) {
+        return Err(WKBError::UnexpectedGeometryType {
+            expected,
+            found: wkb_type,
+            offset,
+            path: vec![],
+        });
+    }
+    Ok(byte_order)
+}
+
+/// Read the SRID of this WKB item if it is EWKB and has an embedded SRID
+pub(crate) fn read_srid(buf: &[u8], byte_order: Endianness, offset: u64) -> WKBResult<Option<i32>> {
+    // Skip 1-byte byte order that we already know
+    let geometry_code = WKBGeometryCode::new(read_u32_at(buf, byte_order, 1 + offset)?);
+    if geometry_code.has_srid() {
+        // The SRID is directly after the geometry code
+        let srid = read_u32_at(buf, byte_order, 5 + offset)?;
+        Ok(Some(srid as i32))
+    } else {
+        Ok(None)
+    }
 }
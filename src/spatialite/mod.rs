@@ -0,0 +1,30 @@
+//! Read and write geometries in the [SpatiaLite BLOB][spec] format.
+//!
+//! SpatiaLite stores each geometry with a header holding the SRID and the minimum bounding
+//! rectangle (MBR), followed by a WKB-like body in which nested geometries have no byte order and
+//! are each marked with an entity byte. LineStrings and Polygon rings may be compressed, storing
+//! all but their first and last points as single-precision deltas.
+//!
+//! [spec]: https://www.gaia-gis.it/gaia-sins/BLOB-Geometry.html
+
+mod reader;
+mod writer;
+
+pub use reader::{read_spatialite_geometry, SpatialiteGeometry};
+pub use writer::{spatialite_geometry_size, write_spatialite_geometry};
+
+/// The first byte of each SpatiaLite geometry
+const START: u8 = 0x00;
+/// The byte after the MBR
+const MBR_END: u8 = 0x7c;
+/// The byte before each nested geometry of a collection
+const ENTITY: u8 = 0x69;
+/// The last byte of each SpatiaLite geometry
+const END: u8 = 0xfe;
+
+/// The number of bytes before the class type of the outermost geometry: the start and byte
+/// order bytes, the SRID, the MBR and its end marker
+const HEADER_SIZE: usize = 39;
+
+/// Added to the class type of a LineString or Polygon whose coordinates are compressed
+const COMPRESSED: u32 = 1_000_000;
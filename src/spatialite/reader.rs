@@ -0,0 +1,260 @@
+use std::mem::discriminant;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+
+use crate::common::{WKBDimension, WKBGeometryCode, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::util::{
+    check_bounds, check_child_dimension, read_byte_order, read_u32_at, read_u8_at,
+};
+use crate::reader::{read_wkb, Wkb};
+use crate::spatialite::*;
+use crate::Endianness;
+
+/// A geometry decoded from a SpatiaLite BLOB.
+///
+/// The geometry is decoded into little-endian ISO WKB, and is accessed through
+/// [`SpatialiteGeometry::geometry`], which implements the `geo_traits` geometry traits.
+#[derive(Debug, Clone)]
+pub struct SpatialiteGeometry {
+    srid: i32,
+    mbr: [f64; 4],
+    wkb: Vec<u8>,
+}
+
+impl SpatialiteGeometry {
+    /// The SRID of this geometry
+    pub fn srid(&self) -> i32 {
+        self.srid
+    }
+
+    /// The minimum bounding rectangle stored in the header, as `[min_x, min_y, max_x, max_y]`
+    pub fn mbr(&self) -> [f64; 4] {
+        self.mbr
+    }
+
+    /// The decoded geometry.
+    pub fn geometry(&self) -> Wkb<'_> {
+        read_wkb(&self.wkb).expect("decoded SpatiaLite geometry is valid WKB")
+    }
+
+    /// The decoded geometry encoded as little-endian ISO WKB.
+    pub fn as_wkb(&self) -> &[u8] {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning it encoded as little-endian ISO WKB.
+    pub fn into_wkb(self) -> Vec<u8> {
+        self.wkb
+    }
+}
+
+/// Decode a geometry from a SpatiaLite BLOB.
+///
+/// Both compressed and uncompressed LineStrings and Polygons are supported.
+pub fn read_spatialite_geometry(buf: &[u8]) -> WKBResult<SpatialiteGeometry> {
+    check_bounds(buf, 0, HEADER_SIZE as u64)?;
+    expect_marker(buf, 0, START)?;
+    let byte_order = read_byte_order(buf, 1)?;
+    let srid = read_u32_at(buf, byte_order, 2)? as i32;
+    let mut mbr = [0.; 4];
+    match byte_order {
+        Endianness::BigEndian => BigEndian::read_f64_into(&buf[6..38], &mut mbr),
+        Endianness::LittleEndian => LittleEndian::read_f64_into(&buf[6..38], &mut mbr),
+    }
+    expect_marker(buf, 38, MBR_END)?;
+
+    let mut decoder = Decoder {
+        buf,
+        byte_order,
+        offset: HEADER_SIZE as u64,
+        out: Vec::new(),
+    };
+    let (wkb_type, compressed) = decoder.read_class_type()?;
+    decoder.read_geometry(wkb_type, compressed)?;
+    expect_marker(buf, decoder.offset, END)?;
+
+    Ok(SpatialiteGeometry {
+        srid,
+        mbr,
+        wkb: decoder.out,
+    })
+}
+
+/// Check that the byte at `offset` is `marker`
+fn expect_marker(buf: &[u8], offset: u64, marker: u8) -> WKBResult<()> {
+    let byte = read_u8_at(buf, offset)?;
+    if byte == marker {
+        Ok(())
+    } else {
+        Err(WKBError::InvalidHeader {
+            reason: format!(
+                "Expected SpatiaLite marker {:#04x} but found {:#04x}",
+                marker, byte
+            )
+            .into(),
+            offset,
+        })
+    }
+}
+
+/// Decodes a SpatiaLite geometry body into WKB written to `out`
+struct Decoder<'a> {
+    buf: &'a [u8],
+    byte_order: Endianness,
+    offset: u64,
+    out: Vec<u8>,
+}
+
+impl Decoder<'_> {
+    /// Read a class type, returning its geometry type and whether its coordinates are compressed
+    fn read_class_type(&mut self) -> WKBResult<(WKBType, bool)> {
+        let offset = self.offset;
+        let code = read_u32_at(self.buf, self.byte_order, offset)?;
+        self.offset += 4;
+
+        let compressed = (COMPRESSED..2 * COMPRESSED).contains(&code);
+        let iso_code = if compressed { code - COMPRESSED } else { code };
+        let wkb_type = WKBGeometryCode::new(iso_code)
+            .get_type()
+            .map_err(|err| err.at_offset(offset))?;
+        let supported = match wkb_type {
+            WKBType::LineString(_) | WKBType::Polygon(_) => true,
+            WKBType::Point(_)
+            | WKBType::MultiPoint(_)
+            | WKBType::MultiLineString(_)
+            | WKBType::MultiPolygon(_)
+            | WKBType::GeometryCollection(_) => !compressed,
+            _ => false,
+        };
+        // SpatiaLite class types are ISO codes, without EWKB flags
+        if !supported || iso_code >= 4000 {
+            return Err(WKBError::UnknownGeometryCode {
+                code,
+                offset,
+                path: vec![],
+            });
+        }
+        Ok((wkb_type, compressed))
+    }
+
+    /// Read the body of a geometry of the given type
+    fn read_geometry(&mut self, wkb_type: WKBType, compressed: bool) -> WKBResult<()> {
+        let dim = wkb_type.dimension();
+        self.out.write_u8(Endianness::LittleEndian.into())?;
+        self.out.write_u32::<LittleEndian>(wkb_type.into())?;
+
+        match wkb_type {
+            WKBType::Point(_) => self.read_coords(dim, 1, false),
+            WKBType::LineString(_) => self.read_line_string(dim, compressed),
+            WKBType::Polygon(_) => {
+                let num_rings = self.read_count(4)?;
+                for i in 0..num_rings {
+                    self.read_line_string(dim, compressed)
+                        .map_err(|err| err.in_part(i))?;
+                }
+                Ok(())
+            }
+            _ => {
+                let num_entities = self.read_count(5)?;
+                for i in 0..num_entities {
+                    self.read_entity(&wkb_type).map_err(|err| err.in_part(i))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Read a nested geometry of the collection of type `parent`
+    fn read_entity(&mut self, parent: &WKBType) -> WKBResult<()> {
+        expect_marker(self.buf, self.offset, ENTITY)?;
+        self.offset += 1;
+
+        let offset = self.offset;
+        let (wkb_type, compressed) = self.read_class_type()?;
+        check_child_dimension(parent, &wkb_type, offset)?;
+        let dim = parent.dimension();
+        let expected = match parent {
+            WKBType::MultiPoint(_) => WKBType::Point(dim),
+            WKBType::MultiLineString(_) => WKBType::LineString(dim),
+            WKBType::MultiPolygon(_) => WKBType::Polygon(dim),
+            // A GeometryCollection holds Points, LineStrings and Polygons
+            _ => match wkb_type {
+                WKBType::Point(_) | WKBType::LineString(_) | WKBType::Polygon(_) => wkb_type,
+                _ => WKBType::Point(dim),
+            },
+        };
+        if discriminant(&expected) != discriminant(&wkb_type) {
+            return Err(WKBError::UnexpectedGeometryType {
+                expected,
+                found: wkb_type,
+                offset,
+                path: vec![],
+            });
+        }
+        self.read_geometry(wkb_type, compressed)
+    }
+
+    /// Read a count of parts and write it to the WKB, checking that the buffer has room for each
+    /// part to take at least `min_part_size` bytes
+    fn read_count(&mut self, min_part_size: u64) -> WKBResult<usize> {
+        let count = read_u32_at(self.buf, self.byte_order, self.offset)?;
+        self.offset += 4;
+        check_bounds(self.buf, self.offset, u64::from(count) * min_part_size)?;
+        self.out.write_u32::<LittleEndian>(count)?;
+        Ok(count as usize)
+    }
+
+    fn read_line_string(&mut self, dim: WKBDimension, compressed: bool) -> WKBResult<()> {
+        // Compressed points take at least four bytes for each value
+        let min_point_size = if compressed { 4 } else { 8 } * dim.size() as u64;
+        let num_points = self.read_count(min_point_size)?;
+        self.read_coords(dim, num_points, compressed)
+    }
+
+    /// Read `num_points` coordinates.
+    ///
+    /// In compressed coordinates every point but the first and last is stored as `f32` deltas
+    /// from the previous point, except for m values which are stored in full.
+    fn read_coords(
+        &mut self,
+        dim: WKBDimension,
+        num_points: usize,
+        compressed: bool,
+    ) -> WKBResult<()> {
+        let mut last = [0.; 4];
+        for i in 0..num_points {
+            let is_full = !compressed || i == 0 || i == num_points - 1;
+            for (n, last) in last.iter_mut().enumerate().take(dim.size()) {
+                let is_m = dim.has_m() && n == dim.size() - 1;
+                if is_full || is_m {
+                    *last = self.read_f64()?;
+                } else {
+                    *last += f64::from(self.read_f32()?);
+                }
+                self.out.write_f64::<LittleEndian>(*last)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_f64(&mut self) -> WKBResult<f64> {
+        check_bounds(self.buf, self.offset, 8)?;
+        let bytes = &self.buf[self.offset as usize..];
+        self.offset += 8;
+        Ok(match self.byte_order {
+            Endianness::BigEndian => BigEndian::read_f64(bytes),
+            Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+        })
+    }
+
+    fn read_f32(&mut self) -> WKBResult<f32> {
+        check_bounds(self.buf, self.offset, 4)?;
+        let bytes = &self.buf[self.offset as usize..];
+        self.offset += 4;
+        Ok(match self.byte_order {
+            Endianness::BigEndian => BigEndian::read_f32(bytes),
+            Endianness::LittleEndian => LittleEndian::read_f32(bytes),
+        })
+    }
+}
@@ -0,0 +1,313 @@
+use std::io::Write;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::bounds::Bounds;
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::spatialite::*;
+use crate::writer::{LineWrapper, RectWrapper, TriangleWrapper};
+use crate::Endianness;
+
+/// The number of bytes this geometry will take up when encoded as a SpatiaLite BLOB
+pub fn spatialite_geometry_size(
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    compressed: bool,
+) -> WKBResult<usize> {
+    let dim = geom.dim().try_into()?;
+    Ok(HEADER_SIZE + entity_size(geom, dim, compressed)? + 1)
+}
+
+/// Write a geometry to a Writer encoded as a SpatiaLite BLOB
+///
+/// If `compressed` is true, LineStrings and Polygon rings are written in the compressed form,
+/// which stores all but the first and last point of each as single-precision deltas. This loses
+/// precision.
+///
+/// SpatiaLite collections cannot be nested, so a GeometryCollection may only contain Points,
+/// LineStrings and Polygons, each with the dimension of the collection.
+pub fn write_spatialite_geometry(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    srid: i32,
+    compressed: bool,
+    endianness: Endianness,
+) -> WKBResult<()> {
+    writer.write_u8(START)?;
+    writer.write_u8(endianness.into())?;
+    match endianness {
+        Endianness::LittleEndian => {
+            write_spatialite_content::<LittleEndian>(writer, geom, srid, compressed)
+        }
+        Endianness::BigEndian => {
+            write_spatialite_content::<BigEndian>(writer, geom, srid, compressed)
+        }
+    }
+}
+
+fn write_spatialite_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    srid: i32,
+    compressed: bool,
+) -> WKBResult<()> {
+    let dim = geom.dim().try_into()?;
+    writer.write_i32::<B>(srid)?;
+
+    // The MBR of an empty geometry is undefined
+    let mut bounds = Bounds::new();
+    bounds.add_geometry(geom);
+    let (min_x, max_x) = bounds.range(0).unwrap_or((f64::NAN, f64::NAN));
+    let (min_y, max_y) = bounds.range(1).unwrap_or((f64::NAN, f64::NAN));
+    for value in [min_x, min_y, max_x, max_y] {
+        writer.write_f64::<B>(value)?;
+    }
+    writer.write_u8(MBR_END)?;
+
+    write_entity::<B>(writer, geom, dim, compressed)?;
+    writer.write_u8(END)?;
+    Ok(())
+}
+
+/// The class type of a geometry, with the compressed flag for LineStrings and Polygons
+fn class_type(wkb_type: WKBType, compressed: bool) -> u32 {
+    let code = wkb_type.into();
+    match wkb_type {
+        WKBType::LineString(_) | WKBType::Polygon(_) if compressed => code + COMPRESSED,
+        _ => code,
+    }
+}
+
+/// The size of the class type and body of `geom`
+fn entity_size(
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> WKBResult<usize> {
+    use GeometryType::*;
+    let size = match geom.as_type() {
+        Point(_) => 4 + 8 * dim.size(),
+        LineString(ls) => 4 + line_string_size(ls, dim, compressed),
+        Polygon(p) => 4 + polygon_size(p, dim, compressed),
+        MultiPoint(mp) => 8 + mp.num_points() * (5 + 8 * dim.size()),
+        MultiLineString(ml) => {
+            8 + ml
+                .line_strings()
+                .map(|ls| 5 + line_string_size(&ls, dim, compressed))
+                .sum::<usize>()
+        }
+        MultiPolygon(mp) => {
+            8 + mp
+                .polygons()
+                .map(|p| 5 + polygon_size(&p, dim, compressed))
+                .sum::<usize>()
+        }
+        GeometryCollection(gc) => {
+            let mut size = 8;
+            for geometry in gc.geometries() {
+                check_collection_member(&geometry, dim)?;
+                size += 1 + entity_size(&geometry, dim, compressed)?;
+            }
+            size
+        }
+        Rect(r) => 4 + polygon_size(&RectWrapper(r), dim, compressed),
+        Triangle(tri) => 4 + polygon_size(&TriangleWrapper(tri), dim, compressed),
+        Line(line) => 4 + line_string_size(&LineWrapper(line), dim, compressed),
+    };
+    Ok(size)
+}
+
+/// The size of the coordinates of a LineString or ring, with its point count
+fn line_string_size(
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> usize {
+    let num_points = geom.num_coords();
+    let full_point_size = 8 * dim.size();
+    if compressed && num_points > 2 {
+        // m values are not compressed
+        let compressed_point_size = if dim.has_m() {
+            4 * (dim.size() - 1) + 8
+        } else {
+            4 * dim.size()
+        };
+        4 + 2 * full_point_size + (num_points - 2) * compressed_point_size
+    } else {
+        4 + num_points * full_point_size
+    }
+}
+
+fn polygon_size(
+    geom: &impl PolygonTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> usize {
+    let rings = geom.exterior().into_iter().chain(geom.interiors());
+    4 + rings
+        .map(|ring| line_string_size(&ring, dim, compressed))
+        .sum::<usize>()
+}
+
+/// Check that a member of a GeometryCollection with dimension `dim` is a Point, LineString or
+/// Polygon with the same dimension
+fn check_collection_member(
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    dim: WKBDimension,
+) -> WKBResult<()> {
+    use GeometryType::*;
+    if let MultiPoint(_) | MultiLineString(_) | MultiPolygon(_) | GeometryCollection(_) =
+        geom.as_type()
+    {
+        return Err(WKBError::IncorrectType(
+            "SpatiaLite collections cannot contain collections".into(),
+        ));
+    }
+    let member_dim: WKBDimension = geom.dim().try_into()?;
+    if member_dim != dim {
+        return Err(WKBError::IncorrectType(
+            format!(
+                "SpatiaLite collection members must have the dimension of the collection, {:?}, not {:?}",
+                dim, member_dim
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Write the class type and body of `geom`
+fn write_entity<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> WKBResult<()> {
+    use GeometryType::*;
+    match geom.as_type() {
+        Point(p) => write_point::<B>(writer, p, dim),
+        LineString(ls) => write_line_string::<B>(writer, ls, dim, compressed),
+        Polygon(p) => write_polygon::<B>(writer, p, dim, compressed),
+        MultiPoint(mp) => {
+            writer.write_u32::<B>(WKBType::MultiPoint(dim).into())?;
+            writer.write_u32::<B>(mp.num_points().try_into().unwrap())?;
+            for point in mp.points() {
+                writer.write_u8(ENTITY)?;
+                write_point::<B>(writer, &point, dim)?;
+            }
+            Ok(())
+        }
+        MultiLineString(ml) => {
+            writer.write_u32::<B>(WKBType::MultiLineString(dim).into())?;
+            writer.write_u32::<B>(ml.num_line_strings().try_into().unwrap())?;
+            for line_string in ml.line_strings() {
+                writer.write_u8(ENTITY)?;
+                write_line_string::<B>(writer, &line_string, dim, compressed)?;
+            }
+            Ok(())
+        }
+        MultiPolygon(mp) => {
+            writer.write_u32::<B>(WKBType::MultiPolygon(dim).into())?;
+            writer.write_u32::<B>(mp.num_polygons().try_into().unwrap())?;
+            for polygon in mp.polygons() {
+                writer.write_u8(ENTITY)?;
+                write_polygon::<B>(writer, &polygon, dim, compressed)?;
+            }
+            Ok(())
+        }
+        GeometryCollection(gc) => {
+            writer.write_u32::<B>(WKBType::GeometryCollection(dim).into())?;
+            writer.write_u32::<B>(gc.num_geometries().try_into().unwrap())?;
+            for geometry in gc.geometries() {
+                check_collection_member(&geometry, dim)?;
+                writer.write_u8(ENTITY)?;
+                write_entity::<B>(writer, &geometry, dim, compressed)?;
+            }
+            Ok(())
+        }
+        Rect(r) => write_polygon::<B>(writer, &RectWrapper(r), dim, compressed),
+        Triangle(tri) => write_polygon::<B>(writer, &TriangleWrapper(tri), dim, compressed),
+        Line(line) => write_line_string::<B>(writer, &LineWrapper(line), dim, compressed),
+    }
+}
+
+fn write_point<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl PointTrait<T: Into<f64>>,
+    dim: WKBDimension,
+) -> WKBResult<()> {
+    writer.write_u32::<B>(WKBType::Point(dim).into())?;
+    match geom.coord() {
+        Some(coord) => {
+            for n in 0..dim.size() {
+                writer.write_f64::<B>(coord.nth_or_panic(n).into())?;
+            }
+        }
+        // An empty point is written with NaN coordinates, as in WKB
+        None => {
+            for _ in 0..dim.size() {
+                writer.write_f64::<B>(f64::NAN)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_line_string<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> WKBResult<()> {
+    writer.write_u32::<B>(class_type(WKBType::LineString(dim), compressed))?;
+    write_coords::<B>(writer, geom, dim, compressed)
+}
+
+fn write_polygon<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl PolygonTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> WKBResult<()> {
+    writer.write_u32::<B>(class_type(WKBType::Polygon(dim), compressed))?;
+    let num_rings = geom.exterior().map_or(0, |_| 1 + geom.num_interiors());
+    writer.write_u32::<B>(num_rings.try_into().unwrap())?;
+    for ring in geom.exterior().into_iter().chain(geom.interiors()) {
+        write_coords::<B>(writer, &ring, dim, compressed)?;
+    }
+    Ok(())
+}
+
+/// Write the point count and coordinates of a LineString or ring
+fn write_coords<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    dim: WKBDimension,
+    compressed: bool,
+) -> WKBResult<()> {
+    let num_points = geom.num_coords();
+    writer.write_u32::<B>(num_points.try_into().unwrap())?;
+
+    // The previous point as it will be decoded, so that errors in the deltas do not accumulate
+    let mut last = [0.; 4];
+    for (i, coord) in geom.coords().enumerate() {
+        let is_full = !compressed || i == 0 || i == num_points - 1;
+        for (n, last) in last.iter_mut().enumerate().take(dim.size()) {
+            let value: f64 = coord.nth_or_panic(n).into();
+            let is_m = dim.has_m() && n == dim.size() - 1;
+            if is_full || is_m {
+                writer.write_f64::<B>(value)?;
+                *last = value;
+            } else {
+                let delta = (value - *last) as f32;
+                writer.write_f32::<B>(delta)?;
+                *last += f64::from(delta);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use geo_traits::to_geo::ToGeoRect;
+use geo_traits::{CoordTrait, RectTrait};
+use geo_types::{coord, Geometry, Rect};
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb_bounds, MAX_NESTING_DEPTH};
+use crate::writer::WriteOptions;
+use crate::{Endianness, WKBDimension, WKBFlavor};
+
+use super::data::*;
+
+#[test]
+fn bounds_2d() {
+    let cases = [
+        (
+            Geometry::Point(point_2d()),
+            Rect::new(coord! { x: 0., y: 1. }, coord! { x: 0., y: 1. }),
+        ),
+        (
+            Geometry::Polygon(polygon_2d_with_interior()),
+            Rect::new(coord! { x: -111., y: 41. }, coord! { x: -104., y: 45. }),
+        ),
+        (
+            Geometry::MultiPolygon(multi_polygon_2d()),
+            Rect::new(coord! { x: -111., y: 41. }, coord! { x: -104., y: 45. }),
+        ),
+    ];
+    for (geom, expected) in cases {
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let options = WriteOptions {
+                endianness,
+                flavor: WKBFlavor::Extended { srid: Some(4326) },
+                ..Default::default()
+            };
+            let bounds = read_wkb_bounds(&to_wkb(&geom, options)).unwrap().unwrap();
+            assert_eq!(bounds.dimension(), WKBDimension::Xy);
+            assert_eq!(bounds.to_rect(), expected);
+        }
+    }
+}
+
+#[test]
+fn bounds_z_and_m() {
+    let geom: wkt::Wkt<f64> =
+        "GEOMETRYCOLLECTION ZM (POINT ZM (1 2 3 4), LINESTRING ZM (-1 5 0 8, 2 -2 9 -4))"
+            .parse()
+            .unwrap();
+    let bounds = read_wkb_bounds(&to_wkb(&geom, Default::default()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(bounds.x_range(), (-1., 2.));
+    assert_eq!(bounds.y_range(), (-2., 5.));
+    assert_eq!(bounds.z_range(), Some((0., 9.)));
+    assert_eq!(bounds.m_range(), Some((-4., 8.)));
+    assert_eq!(bounds.min().nth_or_panic(3), -4.);
+    assert_eq!(bounds.max().nth_or_panic(2), 9.);
+
+    let geom: wkt::Wkt<f64> = "LINESTRING M (1 2 3, 4 5 6)".parse().unwrap();
+    let bounds = read_wkb_bounds(&to_wkb(&geom, Default::default()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(bounds.z_range(), None);
+    assert_eq!(bounds.m_range(), Some((3., 6.)));
+}
+
+#[test]
+fn bounds_skip_empty() {
+    let geom: wkt::Wkt<f64> = "GEOMETRYCOLLECTION (POINT EMPTY, MULTIPOINT (1 2, 3 4))"
+        .parse()
+        .unwrap();
+    let bounds = read_wkb_bounds(&to_wkb(&geom, Default::default()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(bounds.x_range(), (1., 3.));
+    assert_eq!(bounds.y_range(), (2., 4.));
+
+    for empty in ["POINT EMPTY", "POLYGON EMPTY", "GEOMETRYCOLLECTION EMPTY"] {
+        let geom: wkt::Wkt<f64> = empty.parse().unwrap();
+        let buf = to_wkb(&geom, Default::default());
+        assert_eq!(read_wkb_bounds(&buf).unwrap(), None, "{}", empty);
+    }
+}
+
+#[test]
+fn bounds_truncated() {
+    let buf = to_wkb(
+        &Geometry::Polygon(polygon_2d_with_interior()),
+        Default::default(),
+    );
+    for len in 0..buf.len() {
+        assert!(read_wkb_bounds(&buf[..len]).is_err(), "length {} read", len);
+    }
+}
+
+#[test]
+fn bounds_include_every_ring() {
+    // An invalid Polygon whose interior ring extends past its exterior ring
+    let geom: wkt::Wkt<f64> = "POLYGON ((0 0, 1 0, 1 1, 0 0), (0 0, 5 0, 0 -5, 0 0))"
+        .parse()
+        .unwrap();
+    let bounds = read_wkb_bounds(&to_wkb(&geom, Default::default()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(bounds.x_range(), (0., 5.));
+    assert_eq!(bounds.y_range(), (-5., 1.));
+}
+
+#[test]
+fn bounds_deeply_nested() {
+    let bounds = read_wkb_bounds(&nested_collections(MAX_NESTING_DEPTH))
+        .unwrap()
+        .unwrap();
+    assert_eq!(bounds.x_range(), (0., 0.));
+
+    let err = read_wkb_bounds(&nested_collections(5000)).unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+}
@@ -0,0 +1,160 @@
+use geo_traits::{
+    CoordTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait,
+    PointTrait, PolygonTrait,
+};
+use geo_types::Geometry;
+
+use crate::reader::{read_wkb, FlatCoords, Wkb};
+use crate::writer::{write_point, WriteOptions};
+use crate::{Endianness, WKBDimension};
+
+use super::data::*;
+
+/// The values of `coords`, read one at a time through `CoordTrait`
+fn values(coords: impl Iterator<Item = impl CoordTrait<T = f64>>) -> Vec<f64> {
+    coords
+        .flat_map(|coord| (0..coord.dim().size()).map(move |n| coord.nth_or_panic(n)))
+        .collect()
+}
+
+/// Check that decoding `flat` in bulk gives `expected` interleaved values
+fn check_flat_coords(flat: &FlatCoords, expected: &[f64]) {
+    let dim_size = flat.dimension().size();
+    assert_eq!(flat.num_coords() * dim_size, expected.len());
+
+    let mut interleaved = vec![0.; expected.len()];
+    flat.read_interleaved(&mut interleaved);
+    assert_eq!(interleaved, expected);
+
+    let mut columns = vec![Vec::new(); dim_size];
+    flat.extend_columns(&mut columns);
+    for (n, column) in columns.iter().enumerate() {
+        let expected = expected.iter().skip(n).step_by(dim_size);
+        assert!(column.iter().eq(expected));
+    }
+}
+
+#[test]
+fn line_string_flat_coords() {
+    let geom: wkt::Wkt<f64> = "LINESTRING ZM (1 2 3 4, 5 6 7 8, -1 -2 -3 -4)"
+        .parse()
+        .unwrap();
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let buf = to_wkb(
+            &geom,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        );
+        let Wkb::LineString(line_string) = read_wkb(&buf).unwrap() else {
+            panic!("expected a LineString")
+        };
+        let flat = line_string.flat_coords();
+        assert_eq!(flat.dimension(), WKBDimension::Xyzm);
+        check_flat_coords(&flat, &values(line_string.coords()));
+    }
+}
+
+#[test]
+fn polygon_flat_coords() {
+    let geom = Geometry::MultiPolygon(multi_polygon_2d());
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let buf = to_wkb(
+            &geom,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        );
+        let Wkb::MultiPolygon(multi_polygon) = read_wkb(&buf).unwrap() else {
+            panic!("expected a MultiPolygon")
+        };
+
+        let mut expected = Vec::new();
+        for polygon in multi_polygon.polygons() {
+            let mut polygon_values = Vec::new();
+            for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+                check_flat_coords(&ring.flat_coords(), &values(ring.coords()));
+                polygon_values.extend(values(ring.coords()));
+            }
+            check_flat_coords(&polygon.flat_coords(), &polygon_values);
+            expected.extend(polygon_values);
+        }
+        check_flat_coords(&multi_polygon.flat_coords(), &expected);
+    }
+}
+
+#[test]
+fn multi_line_string_flat_coords() {
+    let buf = to_wkb(
+        &Geometry::MultiLineString(multi_line_string_2d()),
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    );
+    let Wkb::MultiLineString(multi_line_string) = read_wkb(&buf).unwrap() else {
+        panic!("expected a MultiLineString")
+    };
+    let expected = values(
+        multi_line_string
+            .line_strings()
+            .flat_map(|ls| ls.coords().collect::<Vec<_>>()),
+    );
+    check_flat_coords(&multi_line_string.flat_coords(), &expected);
+}
+
+#[test]
+fn mixed_endian_multi_point_flat_coords() {
+    // A little-endian MultiPoint with a big-endian Point and a little-endian empty Point
+    let mut buf = vec![0x01, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+    let big_endian = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    write_point(&mut buf, &point_2d(), big_endian).unwrap();
+    let empty: wkt::Wkt<f64> = "POINT EMPTY".parse().unwrap();
+    let wkt::Wkt::Point(empty) = empty else {
+        unreachable!()
+    };
+    write_point(&mut buf, &empty, Default::default()).unwrap();
+
+    let Wkb::MultiPoint(multi_point) = read_wkb(&buf).unwrap() else {
+        panic!("expected a MultiPoint")
+    };
+    assert!(multi_point.point(1).unwrap().coord().is_none());
+
+    let flat = multi_point.flat_coords();
+    assert_eq!(flat.num_coords(), 2);
+    let mut interleaved = vec![-1.];
+    flat.extend_interleaved(&mut interleaved);
+    assert_eq!(interleaved[..3], [-1., 0., 1.]);
+    assert!(interleaved[3..].iter().all(|value| value.is_nan()));
+}
+
+#[test]
+#[should_panic(expected = "output length does not match the number of values")]
+fn flat_coords_wrong_length() {
+    let buf = to_wkb(&Geometry::LineString(linestring_2d()), Default::default());
+    let Wkb::LineString(line_string) = read_wkb(&buf).unwrap() else {
+        panic!("expected a LineString")
+    };
+    line_string.flat_coords().read_interleaved(&mut [0.; 3]);
+}
+
+#[test]
+fn flat_coords_wrong_column_count() {
+    let buf = to_wkb(&Geometry::LineString(linestring_2d()), Default::default());
+    let Wkb::LineString(line_string) = read_wkb(&buf).unwrap() else {
+        panic!("expected a LineString")
+    };
+    let mut columns = vec![vec![1.], vec![2.], vec![3.]];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        line_string.flat_coords().extend_columns(&mut columns)
+    }));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("number of columns does not match the dimension"));
+    // The columns are not changed before panicking
+    assert_eq!(columns, [[1.], [2.], [3.]]);
+}
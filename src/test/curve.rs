@@ -0,0 +1,340 @@
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, LineStringTrait};
+
+use crate::error::WKBError;
+use crate::reader::{read_curved_wkb, read_wkb, CurvedWkb, Wkb, MAX_NESTING_DEPTH};
+use crate::writer::{
+    curved_geometry_wkb_size, write_circular_string, write_curved_geometry, write_line_string,
+    write_polygon, WriteOptions,
+};
+use crate::{Endianness, WKBDimension, WKBFlavor, WKBType};
+
+use super::data::*;
+
+fn circular_string(coords: &[(f64, f64)]) -> Vec<u8> {
+    let geom = geo_types::LineString::from(coords.to_vec());
+    let mut buf = Vec::new();
+    write_circular_string(&mut buf, &geom, Default::default()).unwrap();
+    buf
+}
+
+fn line_string(coords: &[(f64, f64)]) -> Vec<u8> {
+    let geom = geo_types::LineString::from(coords.to_vec());
+    let mut buf = Vec::new();
+    write_line_string(&mut buf, &geom, Default::default()).unwrap();
+    buf
+}
+
+/// A little-endian geometry with the given code containing each of `parts`
+fn collection(code: u32, parts: &[Vec<u8>]) -> Vec<u8> {
+    let mut buf = vec![1];
+    buf.extend_from_slice(&code.to_le_bytes());
+    buf.extend_from_slice(&(parts.len() as u32).to_le_bytes());
+    for part in parts {
+        buf.extend_from_slice(part);
+    }
+    buf
+}
+
+/// A half circle from (0, 0) through (1, 1) to (2, 0)
+fn arc() -> Vec<u8> {
+    circular_string(&[(0., 0.), (1., 1.), (2., 0.)])
+}
+
+/// A closed ring made of a half circle and a straight line back to the start
+fn compound_ring() -> Vec<u8> {
+    collection(9, &[arc(), line_string(&[(2., 0.), (0., 0.)])])
+}
+
+fn all_curves() -> Vec<Vec<u8>> {
+    let mut polygon = Vec::new();
+    write_polygon(&mut polygon, &polygon_2d(), Default::default()).unwrap();
+    let curve_polygon = collection(10, &[compound_ring()]);
+
+    vec![
+        arc(),
+        compound_ring(),
+        curve_polygon.clone(),
+        collection(
+            11,
+            &[line_string(&[(5., 5.), (6., 6.)]), arc(), compound_ring()],
+        ),
+        collection(12, &[polygon, curve_polygon]),
+        curved_geometry_collection(),
+    ]
+}
+
+/// A GeometryCollection with curves both directly within it and in a nested GeometryCollection
+fn curved_geometry_collection() -> Vec<u8> {
+    collection(
+        7,
+        &[
+            line_string(&[(5., 5.), (6., 6.)]),
+            arc(),
+            collection(7, &[compound_ring()]),
+        ],
+    )
+}
+
+#[test]
+fn read_wkb_rejects_curves() {
+    let err = read_wkb(&arc()).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::CurveGeometry {
+            found: WKBType::CircularString(WKBDimension::Xy),
+            offset: 0,
+            ..
+        }
+    ));
+
+    // A CompoundCurve is not mistaken for a Point
+    let err = read_wkb(&compound_ring()).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::CurveGeometry {
+            found: WKBType::CompoundCurve(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn read_curves() {
+    match read_curved_wkb(&compound_ring()).unwrap() {
+        CurvedWkb::CompoundCurve(curve) => {
+            assert_eq!(curve.segments().len(), 2);
+            assert_eq!(curve.dimension(), WKBDimension::Xy);
+        }
+        geom => panic!("unexpected geometry {:?}", geom),
+    }
+
+    // Linear geometries are read as usual
+    let buf = line_string(&[(0., 0.), (1., 1.)]);
+    assert!(matches!(
+        read_curved_wkb(&buf).unwrap(),
+        CurvedWkb::Linear(Wkb::LineString(_))
+    ));
+}
+
+#[test]
+fn read_curved_geometry_collection() {
+    let buf = curved_geometry_collection();
+    let CurvedWkb::GeometryCollection(geom) = read_curved_wkb(&buf).unwrap() else {
+        panic!("expected a GeometryCollection")
+    };
+    let geometries = geom.geometries();
+    assert_eq!(geometries.len(), 3);
+    assert!(matches!(
+        geometries[0],
+        CurvedWkb::Linear(Wkb::LineString(_))
+    ));
+    assert!(matches!(geometries[1], CurvedWkb::CircularString(_)));
+    assert!(matches!(geometries[2], CurvedWkb::GeometryCollection(_)));
+
+    // A GeometryCollection without curves is linear
+    let buf = collection(7, &[line_string(&[(0., 0.), (1., 1.)])]);
+    assert!(matches!(
+        read_curved_wkb(&buf).unwrap(),
+        CurvedWkb::Linear(Wkb::GeometryCollection(_))
+    ));
+
+    let err = read_curved_wkb(&nested_collections(5000)).err().unwrap();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert_eq!(err.path().unwrap().len(), MAX_NESTING_DEPTH + 1);
+}
+
+#[test]
+fn invalid_curve_parts() {
+    // A CompoundCurve cannot contain another CompoundCurve
+    let buf = collection(9, &[compound_ring()]);
+    let err = read_curved_wkb(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedGeometryType { .. }));
+    assert_eq!(err.path(), Some([0].as_slice()));
+
+    // A MultiSurface cannot contain a CircularString
+    let buf = collection(12, &[arc()]);
+    assert!(matches!(
+        read_curved_wkb(&buf),
+        Err(WKBError::UnexpectedGeometryType { .. })
+    ));
+
+    for buf in all_curves() {
+        for len in 0..buf.len() {
+            assert!(read_curved_wkb(&buf[..len]).is_err());
+        }
+    }
+}
+
+#[test]
+fn round_trip_curves() {
+    for buf in all_curves() {
+        let geom = read_curved_wkb(&buf).unwrap();
+        assert_eq!(geom.size(), buf.len() as u64);
+        assert_eq!(
+            curved_geometry_wkb_size(&geom, Default::default()),
+            buf.len()
+        );
+
+        // Rewriting to big endian and back is lossless
+        let mut big_endian = Vec::new();
+        write_curved_geometry(
+            &mut big_endian,
+            &geom,
+            WriteOptions {
+                endianness: Endianness::BigEndian,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let geom = read_curved_wkb(&big_endian).unwrap();
+        let mut retour = Vec::new();
+        write_curved_geometry(&mut retour, &geom, Default::default()).unwrap();
+        assert_eq!(buf, retour);
+    }
+}
+
+#[test]
+fn round_trip_ewkb_circular_string_z() {
+    let coords = [(0., 0., 0.), (1., 1., 10.), (2., 0., 20.)]
+        .map(|(x, y, z)| wkt::types::Coord {
+            x,
+            y,
+            z: Some(z),
+            m: None,
+        })
+        .to_vec();
+    let geom = wkt::types::LineString(coords);
+
+    let mut buf = Vec::new();
+    let options = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    write_circular_string(&mut buf, &geom, options).unwrap();
+    assert_eq!(&buf[1..5], &0xA0000008_u32.to_le_bytes());
+
+    let curve = read_curved_wkb(&buf).unwrap();
+    assert_eq!(curve.srid(), Some(4326));
+    assert_eq!(curve.dimension(), WKBDimension::Xyz);
+
+    let mut retour = Vec::new();
+    write_curved_geometry(&mut retour, &curve, options).unwrap();
+    assert_eq!(buf, retour);
+
+    // Z values are interpolated along the arc
+    let linear = curve.linearize(0.01).unwrap();
+    let geom = read_wkb(&linear).unwrap();
+    let GeometryType::LineString(ls) = geom.as_type() else {
+        panic!("expected a LineString");
+    };
+    let z = ls.coords().map(|c| c.nth_or_panic(2)).collect::<Vec<_>>();
+    assert!(z.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(z.first(), Some(&0.));
+    assert_eq!(z.last(), Some(&20.));
+}
+
+#[test]
+fn linearize_arc() {
+    let tolerance = 0.001;
+    let linear = read_curved_wkb(&arc())
+        .unwrap()
+        .linearize(tolerance)
+        .unwrap();
+    let geom = read_wkb(&linear).unwrap();
+    let GeometryType::LineString(ls) = geom.as_type() else {
+        panic!("expected a LineString");
+    };
+
+    let coords = ls.coords().map(|c| (c.x(), c.y())).collect::<Vec<_>>();
+    assert!(coords.len() > 3);
+    assert_eq!(coords.first(), Some(&(0., 0.)));
+    assert_eq!(coords.last(), Some(&(2., 0.)));
+    assert!(coords.contains(&(1., 1.)));
+
+    // Every point is on the circle, and every chord is within the tolerance of it
+    for (x, y) in &coords {
+        approx::assert_relative_eq!((x - 1.).hypot(*y), 1., epsilon = 1e-12);
+    }
+    for pair in coords.windows(2) {
+        let (x, y) = ((pair[0].0 + pair[1].0) / 2., (pair[0].1 + pair[1].1) / 2.);
+        assert!(1. - (x - 1.).hypot(y) <= tolerance);
+    }
+}
+
+#[test]
+fn linearize_curve_polygon() {
+    let buf = all_curves().remove(4);
+    let curve = read_curved_wkb(&buf).unwrap();
+    let linear = curve.linearize(0.01).unwrap();
+    let geom = read_wkb(&linear).unwrap();
+    let GeometryType::MultiPolygon(_) = geom.as_type() else {
+        panic!("expected a MultiPolygon");
+    };
+    let geo_types::Geometry::MultiPolygon(multi_polygon) =
+        geo_traits::to_geo::ToGeoGeometry::to_geometry(&geom)
+    else {
+        unreachable!()
+    };
+    assert_eq!(multi_polygon.0[0], polygon_2d());
+
+    // The exterior ring is closed, with the half circle joined to the straight segment
+    let ring = multi_polygon.0[1].exterior();
+    assert!(ring.is_closed());
+    assert_eq!(ring.0.iter().filter(|c| **c == (2., 0.).into()).count(), 1);
+
+    assert!(curve.linearize(0.).is_err());
+    assert!(curve.linearize(f64::NAN).is_err());
+}
+
+#[test]
+fn linearize_curved_geometry_collection() {
+    let buf = curved_geometry_collection();
+    let curve = read_curved_wkb(&buf).unwrap();
+    let linear = curve.linearize(0.01).unwrap();
+    let geo_types::Geometry::GeometryCollection(collection) =
+        geo_traits::to_geo::ToGeoGeometry::to_geometry(&read_wkb(&linear).unwrap())
+    else {
+        panic!("expected a GeometryCollection");
+    };
+    assert!(matches!(
+        collection.0[0],
+        geo_types::Geometry::LineString(_)
+    ));
+    assert!(matches!(
+        collection.0[1],
+        geo_types::Geometry::LineString(_)
+    ));
+    let geo_types::Geometry::GeometryCollection(nested) = &collection.0[2] else {
+        panic!("expected a nested GeometryCollection");
+    };
+    assert!(matches!(nested.0[0], geo_types::Geometry::LineString(_)));
+}
+
+#[test]
+fn linearize_huge_arc_errors() {
+    let buf = circular_string(&[(0., 0.), (3.7e19, 3.7e19), (7.4e19, 0.)]);
+    let curve = read_curved_wkb(&buf).unwrap();
+    assert!(matches!(curve.linearize(0.1), Err(WKBError::General(_))));
+}
+
+#[test]
+fn linearize_many_large_arcs_errors() {
+    // Each half circle needs about 2^16 segments, within the limit on its own
+    let radius = 3.5e8;
+    let arcs = |num_arcs: usize| {
+        let mut coords = vec![(0., 0.)];
+        for i in 0..num_arcs {
+            let x = 2. * radius * i as f64;
+            coords.push((x + radius, radius));
+            coords.push((x + 2. * radius, 0.));
+        }
+        circular_string(&coords)
+    };
+    let buf = arcs(1);
+    assert!(read_curved_wkb(&buf).unwrap().linearize(0.1).is_ok());
+
+    // Together the arcs need more than 2^20 segments
+    let buf = arcs(20);
+    let curve = read_curved_wkb(&buf).unwrap();
+    assert!(matches!(curve.linearize(0.1), Err(WKBError::General(_))));
+}
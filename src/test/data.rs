@@ -1,8 +1,19 @@
+use geo_traits::GeometryTrait;
 use geo_types::{
     line_string, point, polygon, Geometry, GeometryCollection, LineString, MultiLineString,
     MultiPoint, MultiPolygon, Point, Polygon,
 };
 
+use crate::writer::{geometry_wkb_size, write_geometry, WriteOptions};
+
+/// Write `geom` as WKB with `options`, checking the size it was expected to take up
+pub(super) fn to_wkb(geom: &impl GeometryTrait<T = f64>, options: WriteOptions) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geom, options).unwrap();
+    assert_eq!(buf.len(), geometry_wkb_size(geom, options));
+    buf
+}
+
 pub(super) fn point_2d() -> Point {
     point!(
         x: 0., y: 1.
@@ -110,3 +121,11 @@ pub(super) fn geometry_collection_2d() -> GeometryCollection {
         Geometry::MultiPolygon(multi_polygon_2d()),
     ])
 }
+
+/// The WKB of a Point nested in `depth` GeometryCollections of one geometry each
+pub(super) fn nested_collections(depth: usize) -> Vec<u8> {
+    let mut buf = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+    buf.extend_from_slice(&[1, 1, 0, 0, 0]);
+    buf.extend_from_slice(&[0; 16]);
+    buf
+}
@@ -3,6 +3,8 @@ use geo_types::Geometry;
 use geos::WKBWriter;
 
 use crate::reader::read_wkb;
+use crate::writer::{geometry_wkb_size, write_geometry, WriteOptions};
+use crate::{Endianness, WKBFlavor};
 
 use super::data::*;
 
@@ -17,6 +19,7 @@ fn read_point_srid() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Point(orig), retour.to_geometry());
 }
 
@@ -32,6 +35,7 @@ fn read_line_string_srid() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::LineString(orig.clone()), retour.to_geometry());
 }
 
@@ -47,6 +51,7 @@ fn read_polygon() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 }
 
@@ -62,6 +67,7 @@ fn read_polygon_with_interior() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 }
 
@@ -77,6 +83,7 @@ fn read_multi_point() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::MultiPoint(orig.clone()), retour.to_geometry());
 }
 
@@ -92,6 +99,7 @@ fn read_multi_line_string() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(
         Geometry::MultiLineString(orig.clone()),
         retour.to_geometry()
@@ -110,6 +118,7 @@ fn read_multi_polygon() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(Geometry::MultiPolygon(orig.clone()), retour.to_geometry());
 }
 
@@ -144,8 +153,95 @@ fn read_geometry_collection() {
     let buf: Vec<u8> = wkb_writer.write_wkb(&geos_geom).unwrap().into();
 
     let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(1));
     assert_eq!(
         Geometry::GeometryCollection(orig.clone()),
         retour.to_geometry()
     );
 }
+
+#[test]
+fn read_srid_values() {
+    // Little-endian EWKB POINT(1 2) with SRID 4326
+    let mut buf = vec![1];
+    buf.extend_from_slice(&0x20000001_u32.to_le_bytes());
+    buf.extend_from_slice(&4326_u32.to_le_bytes());
+    buf.extend_from_slice(&1.0_f64.to_le_bytes());
+    buf.extend_from_slice(&2.0_f64.to_le_bytes());
+
+    let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(4326));
+    assert_eq!(retour.to_geometry(), Geometry::Point((1., 2.).into()));
+
+    // The same point without an SRID
+    let mut buf = vec![1];
+    buf.extend_from_slice(&1_u32.to_le_bytes());
+    buf.extend_from_slice(&1.0_f64.to_le_bytes());
+    buf.extend_from_slice(&2.0_f64.to_le_bytes());
+    assert_eq!(read_wkb(&buf).unwrap().srid(), None);
+}
+
+fn all_geometries() -> Vec<Geometry> {
+    vec![
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(geometry_collection_2d()),
+    ]
+}
+
+#[test]
+fn round_trip_ewkb() {
+    for orig in all_geometries() {
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            for srid in [None, Some(4326)] {
+                let options = WriteOptions {
+                    endianness,
+                    flavor: WKBFlavor::Extended { srid },
+                    ..Default::default()
+                };
+                let mut buf = Vec::new();
+                write_geometry(&mut buf, &orig, options).unwrap();
+                assert_eq!(buf.len(), geometry_wkb_size(&orig, options));
+
+                let retour = read_wkb(&buf).unwrap();
+                assert_eq!(retour.srid(), srid);
+                assert_eq!(retour.size(), buf.len() as u64);
+                assert_eq!(orig, retour.to_geometry());
+            }
+        }
+    }
+}
+
+#[test]
+fn write_ewkb_point_z() {
+    let orig = geo_types::Point::new(1., 2.);
+    let coord = wkt::types::Coord {
+        x: 1.,
+        y: 2.,
+        z: Some(3.),
+        m: None,
+    };
+    let point_z = wkt::types::Point(Some(coord));
+
+    let mut buf = Vec::new();
+    let options = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    write_geometry(&mut buf, &point_z, options).unwrap();
+
+    // Z and SRID flags are set on the Point code
+    assert_eq!(&buf[1..5], &0xA0000001_u32.to_le_bytes());
+    assert_eq!(&buf[5..9], &4326_i32.to_le_bytes());
+    assert_eq!(buf.len(), 9 + 3 * 8);
+
+    let retour = read_wkb(&buf).unwrap();
+    assert_eq!(retour.srid(), Some(4326));
+    assert_eq!(retour.dimension(), crate::WKBDimension::Xyz);
+    assert_eq!(Geometry::Point(orig), retour.to_geometry());
+}
@@ -0,0 +1,169 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::{point, Geometry};
+
+use crate::error::WKBError;
+use crate::gpkg::{gpkg_geometry_size, read_gpkg_geometry, write_gpkg_geometry, GpkgEnvelope};
+use crate::reader::read_wkb;
+use crate::writer::write_geometry;
+use crate::{Endianness, WKBDimension};
+
+use super::data::*;
+
+#[test]
+fn read_point_blob() {
+    // POINT(1 2) in EPSG:4326, little endian without an envelope, as written by GDAL
+    let mut blob = vec![b'G', b'P', 0x00, 0x01, 0xe6, 0x10, 0x00, 0x00];
+    let point = Geometry::Point(point!(x: 1., y: 2.));
+    write_geometry(&mut blob, &point, Default::default()).unwrap();
+
+    let geometry = read_gpkg_geometry(&blob).unwrap();
+    assert_eq!(geometry.srs_id(), 4326);
+    assert_eq!(geometry.envelope(), None);
+    assert!(!geometry.is_empty());
+    assert!(!geometry.is_extended());
+    assert_eq!(geometry.header_size(), 8);
+    assert_eq!(geometry.wkb(), &blob[8..]);
+    assert_eq!(geometry.geometry().to_geometry(), point);
+}
+
+#[test]
+fn round_trip_with_envelope() {
+    let polygon = Geometry::Polygon(polygon_2d_with_interior());
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let mut blob = Vec::new();
+        write_gpkg_geometry(
+            &mut blob,
+            &polygon,
+            4326,
+            Some(WKBDimension::Xy),
+            endianness,
+        )
+        .unwrap();
+        assert_eq!(
+            blob.len(),
+            gpkg_geometry_size(&polygon, Some(WKBDimension::Xy))
+        );
+        assert_eq!(&blob[..3], b"GP\0");
+        assert_eq!(blob[3] & 0x0e, 0x02);
+
+        let geometry = read_gpkg_geometry(&blob).unwrap();
+        assert_eq!(geometry.srs_id(), 4326);
+        assert_eq!(
+            geometry.envelope(),
+            Some(&GpkgEnvelope {
+                min_x: -111.,
+                max_x: -104.,
+                min_y: 41.,
+                max_y: 45.,
+                z: None,
+                m: None,
+            })
+        );
+        assert_eq!(geometry.header_size(), 40);
+        assert_eq!(geometry.geometry().to_geometry(), polygon);
+    }
+}
+
+#[test]
+fn xyzm_envelope() {
+    let geom: wkt::Wkt<f64> = "LINESTRING ZM (1 2 3 4, 5 -6 7 -8)".parse().unwrap();
+    let mut blob = Vec::new();
+    for dim in [
+        WKBDimension::Xy,
+        WKBDimension::Xyz,
+        WKBDimension::Xym,
+        WKBDimension::Xyzm,
+    ] {
+        blob.clear();
+        write_gpkg_geometry(&mut blob, &geom, 0, Some(dim), Endianness::LittleEndian).unwrap();
+        assert_eq!(blob.len(), gpkg_geometry_size(&geom, Some(dim)));
+
+        let geometry = read_gpkg_geometry(&blob).unwrap();
+        let envelope = geometry.envelope().unwrap();
+        assert_eq!(envelope.dimension(), dim);
+        assert_eq!((envelope.min_y, envelope.max_y), (-6., 2.));
+        if dim.has_z() {
+            assert_eq!(envelope.z, Some((3., 7.)));
+        }
+        if dim.has_m() {
+            assert_eq!(envelope.m, Some((-8., 4.)));
+        }
+        assert_eq!(geometry.geometry().dimension(), WKBDimension::Xyzm);
+    }
+
+    // A 2D geometry has no z range
+    let err = write_gpkg_geometry(
+        &mut blob,
+        &polygon_2d(),
+        0,
+        Some(WKBDimension::Xyz),
+        Endianness::LittleEndian,
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, WKBError::IncorrectType(_)));
+}
+
+#[test]
+fn empty_geometry() {
+    let geom: wkt::Wkt<f64> = "POINT EMPTY".parse().unwrap();
+    let mut blob = Vec::new();
+    write_gpkg_geometry(
+        &mut blob,
+        &geom,
+        4326,
+        Some(WKBDimension::Xy),
+        Endianness::LittleEndian,
+    )
+    .unwrap();
+
+    let geometry = read_gpkg_geometry(&blob).unwrap();
+    assert!(geometry.is_empty());
+    assert!(geometry.envelope().unwrap().min_x.is_nan());
+    assert!(read_wkb(geometry.wkb()).is_ok());
+}
+
+#[test]
+fn invalid_header() {
+    let mut blob = Vec::new();
+    write_gpkg_geometry(
+        &mut blob,
+        &polygon_2d(),
+        4326,
+        Some(WKBDimension::Xy),
+        Endianness::BigEndian,
+    )
+    .unwrap();
+    for len in 0..blob.len() {
+        assert!(read_gpkg_geometry(&blob[..len]).is_err());
+    }
+
+    let mut bad_magic = blob.clone();
+    bad_magic[1] = b'X';
+    assert!(matches!(
+        read_gpkg_geometry(&bad_magic),
+        Err(WKBError::InvalidHeader { offset: 0, .. })
+    ));
+
+    let mut bad_version = blob.clone();
+    bad_version[2] = 1;
+    assert!(matches!(
+        read_gpkg_geometry(&bad_version),
+        Err(WKBError::InvalidHeader { offset: 2, .. })
+    ));
+
+    let mut bad_envelope = blob.clone();
+    bad_envelope[3] = 5 << 1;
+    assert!(matches!(
+        read_gpkg_geometry(&bad_envelope),
+        Err(WKBError::InvalidHeader { offset: 3, .. })
+    ));
+
+    // Offsets into the WKB are from the start of the blob
+    let mut bad_wkb = blob.clone();
+    bad_wkb[40] = 2;
+    assert!(matches!(
+        read_gpkg_geometry(&bad_wkb),
+        Err(WKBError::InvalidByteOrder { offset: 40, .. })
+    ));
+}
@@ -0,0 +1,100 @@
+use geo_traits::to_geo::ToGeoGeometry;
+
+use crate::error::WKBError;
+use crate::reader::read_hex_wkb;
+use crate::writer::{geometry_to_hex, write_geometry, write_geometry_hex, WriteOptions};
+use crate::{Endianness, WKBFlavor};
+
+use super::data::*;
+
+/// `SELECT ST_SetSRID(ST_MakePoint(1, 2), 4326)` as printed by PostGIS
+const POINT_EWKB_HEX: &str = "0101000020E6100000000000000000F03F0000000000000040";
+
+#[test]
+fn read_postgis_hex_ewkb() {
+    let mut buf = Vec::new();
+    let geom = read_hex_wkb(POINT_EWKB_HEX, &mut buf).unwrap();
+    assert_eq!(geom.srid(), Some(4326));
+    assert_eq!(
+        geom.to_geometry(),
+        geo_types::Geometry::Point(geo_types::point!(x: 1., y: 2.))
+    );
+
+    // Lower-case digits and a bytea prefix decode to the same geometry
+    let lower = format!("\\x{}", POINT_EWKB_HEX.to_lowercase());
+    let mut other = Vec::new();
+    read_hex_wkb(&lower, &mut other).unwrap();
+    assert_eq!(buf, other);
+}
+
+#[test]
+fn write_hex_ewkb() {
+    let geom = geo_types::Geometry::Point(geo_types::point!(x: 1., y: 2.));
+    let options = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    let hex = geometry_to_hex(&geom, options).unwrap();
+    assert_eq!(hex, POINT_EWKB_HEX);
+
+    // Hex is appended to any existing content
+    let mut out = String::from("geom=");
+    write_geometry_hex(&mut out, &geom, options).unwrap();
+    assert_eq!(out, format!("geom={POINT_EWKB_HEX}"));
+}
+
+#[test]
+fn round_trip_hex() {
+    let orig = geo_types::Geometry::MultiPolygon(multi_polygon_2d());
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let hex = geometry_to_hex(
+            &orig,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let mut wkb = Vec::new();
+        write_geometry(
+            &mut wkb,
+            &orig,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(hex.len(), 2 * wkb.len());
+
+        let mut buf = Vec::new();
+        let retour = read_hex_wkb(&hex, &mut buf).unwrap();
+        assert_eq!(retour.to_geometry(), orig);
+        assert_eq!(buf, wkb);
+    }
+}
+
+#[test]
+fn invalid_hex() {
+    let mut buf = Vec::new();
+
+    let bad_digit = POINT_EWKB_HEX.replacen("E6", "G6", 1);
+    assert!(matches!(
+        read_hex_wkb(&bad_digit, &mut buf),
+        Err(WKBError::InvalidHex { offset: 10 })
+    ));
+
+    let odd = &POINT_EWKB_HEX[..POINT_EWKB_HEX.len() - 1];
+    assert!(matches!(
+        read_hex_wkb(odd, &mut buf),
+        Err(WKBError::InvalidHex { offset: 49 })
+    ));
+
+    // Valid hex that is not a complete geometry
+    let truncated = &POINT_EWKB_HEX[..POINT_EWKB_HEX.len() - 2];
+    assert!(matches!(
+        read_hex_wkb(truncated, &mut buf),
+        Err(WKBError::UnexpectedEof { .. })
+    ));
+}
@@ -0,0 +1,103 @@
+use geo_types::Geometry;
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, read_wkb_info, MAX_NESTING_DEPTH};
+use crate::writer::WriteOptions;
+use crate::{Endianness, WKBDimension, WKBFlavor, WKBType};
+
+use super::data::*;
+
+#[test]
+fn polygon_info() {
+    let options = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    let buf = to_wkb(&Geometry::Polygon(polygon_2d_with_interior()), options);
+    let info = read_wkb_info(&buf).unwrap();
+    assert_eq!(info.wkb_type(), WKBType::Polygon(WKBDimension::Xy));
+    assert_eq!(info.dimension(), WKBDimension::Xy);
+    assert_eq!(info.byte_order(), Endianness::BigEndian);
+    assert_eq!(info.flavor(), WKBFlavor::Iso);
+    assert_eq!(info.srid(), None);
+    assert_eq!(info.num_parts(), None);
+    assert_eq!(info.num_rings(), 2);
+    assert_eq!(info.num_coords(), 10);
+    assert_eq!(info.size(), buf.len() as u64);
+}
+
+#[test]
+fn geometry_collection_info() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let options = WriteOptions {
+            endianness,
+            flavor: WKBFlavor::Extended { srid: Some(4326) },
+            ..Default::default()
+        };
+        let buf = to_wkb(
+            &Geometry::GeometryCollection(geometry_collection_2d()),
+            options,
+        );
+        let info = read_wkb_info(&buf).unwrap();
+        assert_eq!(info.byte_order(), endianness);
+        assert_eq!(info.flavor(), options.flavor);
+        assert_eq!(info.srid(), Some(4326));
+        assert_eq!(info.num_parts(), Some(7));
+        assert_eq!(info.num_rings(), 6);
+        assert_eq!(info.num_coords(), 43);
+        assert_eq!(info.size(), read_wkb(&buf).unwrap().size());
+    }
+}
+
+#[test]
+fn empty_point_info() {
+    let geom: wkt::Wkt<f64> = "GEOMETRYCOLLECTION (MULTIPOINT (1 2, 4 5), POINT EMPTY)"
+        .parse()
+        .unwrap();
+    let info = read_wkb_info(&to_wkb(&geom, Default::default())).unwrap();
+    assert_eq!(info.num_parts(), Some(2));
+    // The empty Point has no coordinate
+    assert_eq!(info.num_coords(), 2);
+}
+
+#[test]
+fn extended_flavor_info() {
+    let geom: wkt::Wkt<f64> = "POINT Z (1 2 3)".parse().unwrap();
+    let info = read_wkb_info(&to_wkb(&geom, Default::default())).unwrap();
+    assert_eq!(info.wkb_type(), WKBType::Point(WKBDimension::Xyz));
+    assert_eq!(info.flavor(), WKBFlavor::Iso);
+
+    // The z flag marks the geometry as extended WKB even without an SRID
+    let options = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: None },
+        ..Default::default()
+    };
+    let info = read_wkb_info(&to_wkb(&geom, options)).unwrap();
+    assert_eq!(info.wkb_type(), WKBType::Point(WKBDimension::Xyz));
+    assert_eq!(info.flavor(), WKBFlavor::Extended { srid: None });
+    assert_eq!(info.num_coords(), 1);
+}
+
+#[test]
+fn truncated_info() {
+    let buf = to_wkb(
+        &Geometry::MultiPolygon(multi_polygon_2d()),
+        Default::default(),
+    );
+    let err = read_wkb_info(&buf[..buf.len() - 1]).unwrap_err();
+    assert!(matches!(err, WKBError::UnexpectedEof { .. }));
+    assert_eq!(err.path(), Some(&[1, 1][..]));
+}
+
+#[test]
+fn deeply_nested_info() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    let info = read_wkb_info(&buf).unwrap();
+    assert_eq!(info.num_parts(), Some(1));
+    assert_eq!(info.num_coords(), 1);
+    assert_eq!(info.size(), buf.len() as u64);
+
+    let err = read_wkb_info(&nested_collections(5000)).unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert_eq!(err.path().unwrap().len(), MAX_NESTING_DEPTH + 1);
+}
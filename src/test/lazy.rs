@@ -0,0 +1,148 @@
+use geo_traits::to_geo::{ToGeoGeometry, ToGeoLineString};
+use geo_types::Geometry;
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, read_wkb_lazy, LazyWkb, MAX_NESTING_DEPTH};
+use crate::writer::WriteOptions;
+use crate::{Endianness, WKBType};
+
+use super::data::*;
+
+/// Check that `lazy` reads the same geometry as the eager reader
+fn check_same(lazy: LazyWkb, expected: &Geometry) {
+    let parsed = lazy.parse().unwrap();
+    assert_eq!(&parsed.to_geometry(), expected);
+    assert_eq!(lazy.size().unwrap(), parsed.size());
+    assert_eq!(lazy.dimension(), parsed.dimension());
+}
+
+#[test]
+fn parts_match_eager() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        for geom in [
+            Geometry::MultiPoint(multi_point_2d()),
+            Geometry::MultiLineString(multi_line_string_2d()),
+            Geometry::MultiPolygon(multi_polygon_2d()),
+            Geometry::GeometryCollection(geometry_collection_2d()),
+        ] {
+            let buf = to_wkb(
+                &geom,
+                WriteOptions {
+                    endianness,
+                    ..Default::default()
+                },
+            );
+            let lazy = read_wkb_lazy(&buf).unwrap();
+            check_same(lazy, &geom);
+
+            let eager = read_wkb(&buf).unwrap().to_geometry();
+            let expected: Vec<Geometry> = match eager {
+                Geometry::MultiPoint(g) => g.into_iter().map(Geometry::Point).collect(),
+                Geometry::MultiLineString(g) => g.into_iter().map(Geometry::LineString).collect(),
+                Geometry::MultiPolygon(g) => g.into_iter().map(Geometry::Polygon).collect(),
+                Geometry::GeometryCollection(g) => g.into_iter().collect(),
+                _ => unreachable!(),
+            };
+            let parts = lazy.parts().unwrap();
+            assert_eq!(parts.num_parts(), expected.len());
+            for (part, expected) in parts.zip(&expected) {
+                check_same(part.unwrap(), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn rings_match_eager() {
+    let geom = Geometry::Polygon(polygon_2d_with_interior());
+    let buf = to_wkb(
+        &geom,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    );
+    let lazy = read_wkb_lazy(&buf).unwrap();
+    check_same(lazy, &geom);
+
+    let Geometry::Polygon(polygon) = geom else {
+        unreachable!()
+    };
+    let rings = lazy.rings().unwrap();
+    assert_eq!(rings.num_rings(), 1 + polygon.interiors().len());
+    let expected = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+    for (ring, expected) in rings.zip(expected) {
+        assert_eq!(&ring.unwrap().to_line_string(), expected);
+    }
+}
+
+#[test]
+fn reads_only_what_is_needed() {
+    let geom = Geometry::MultiLineString(multi_line_string_2d());
+    let buf = to_wkb(&geom, Default::default());
+    let truncated = &buf[..buf.len() - 1];
+    assert!(read_wkb(truncated).is_err());
+
+    // The header and the first part can still be read
+    let lazy = read_wkb_lazy(truncated).unwrap();
+    assert!(matches!(lazy.wkb_type(), WKBType::MultiLineString(_)));
+    let mut parts = lazy.parts().unwrap();
+    let first = parts.next().unwrap().unwrap();
+    assert!(first.parse().is_ok());
+
+    // Walking to the end finds the truncated last part
+    let last = parts.num_parts() - 1;
+    let err = lazy.size().unwrap_err();
+    assert!(matches!(err, WKBError::UnexpectedEof { .. }));
+    assert_eq!(err.path(), Some(&[last][..]));
+}
+
+#[test]
+fn parts_stop_after_error() {
+    let geom = Geometry::GeometryCollection(geometry_collection_2d());
+    let mut buf = to_wkb(&geom, Default::default());
+    // Give the first part an invalid byte order
+    buf[9] = 2;
+    let lazy = read_wkb_lazy(&buf).unwrap();
+    let parts = lazy.parts().unwrap().collect::<Vec<_>>();
+    assert_eq!(parts.len(), 1);
+    assert!(matches!(parts[0], Err(WKBError::InvalidByteOrder { .. })));
+}
+
+#[test]
+fn no_parts_or_rings() {
+    let buf = to_wkb(&Geometry::Point(point_2d()), Default::default());
+    let lazy = read_wkb_lazy(&buf).unwrap();
+    assert!(matches!(lazy.parts(), Err(WKBError::IncorrectType(_))));
+    assert!(matches!(lazy.rings(), Err(WKBError::IncorrectType(_))));
+    assert_eq!(lazy.size().unwrap(), buf.len() as u64);
+}
+
+#[test]
+fn deeply_nested_collections_error() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    let lazy = read_wkb_lazy(&buf).unwrap();
+    assert_eq!(lazy.size().unwrap(), buf.len() as u64);
+    assert!(lazy.parse().is_ok());
+
+    for depth in [MAX_NESTING_DEPTH + 1, 5000] {
+        let buf = nested_collections(depth);
+        let lazy = read_wkb_lazy(&buf).unwrap();
+        let err = lazy.size().unwrap_err();
+        assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+        assert_eq!(err.path().unwrap().len(), MAX_NESTING_DEPTH + 1);
+    }
+
+    // A part keeps the depth it is nested at
+    let buf = nested_collections(MAX_NESTING_DEPTH + 1);
+    let mut part = read_wkb_lazy(&buf).unwrap();
+    for _ in 0..MAX_NESTING_DEPTH {
+        part = part.parts().unwrap().next().unwrap().unwrap();
+    }
+    let err = part.parts().unwrap().next().unwrap().unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert!(matches!(
+        part.parse(),
+        Err(WKBError::TooDeeplyNested { .. })
+    ));
+}
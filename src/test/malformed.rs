@@ -0,0 +1,207 @@
+use geo_traits::to_geo::ToGeoGeometry;
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, read_wkb_prefix, read_wkb_strict, MAX_NESTING_DEPTH};
+use crate::writer::{polygon_wkb_size, write_geometry, write_multi_polygon, WriteOptions};
+use crate::{Endianness, WKBDimension};
+
+use super::data::*;
+
+fn geometry_collection_buffers() -> Vec<Vec<u8>> {
+    let geom = geo_types::Geometry::GeometryCollection(geometry_collection_2d());
+    [Endianness::LittleEndian, Endianness::BigEndian]
+        .into_iter()
+        .map(|endianness| {
+            let mut buf = Vec::new();
+            write_geometry(
+                &mut buf,
+                &geom,
+                WriteOptions {
+                    endianness,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            buf
+        })
+        .collect()
+}
+
+#[test]
+fn truncated_buffers_error() {
+    for buf in geometry_collection_buffers() {
+        for len in 0..buf.len() {
+            assert!(read_wkb(&buf[..len]).is_err(), "length {} parsed", len);
+        }
+    }
+}
+
+#[test]
+fn invalid_byte_order_errors() {
+    let mut buf = geometry_collection_buffers().remove(0);
+    buf[0] = 2;
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn huge_counts_error() {
+    // LineString with numPoints = u32::MAX and no coordinates
+    let buf = [1, 2, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    assert!(read_wkb(&buf).is_err());
+
+    // MultiPolygon with numPolygons = u32::MAX and no polygons
+    let buf = [1, 6, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    assert!(read_wkb(&buf).is_err());
+
+    // GeometryCollection with numGeometries = u32::MAX and no geometries
+    let buf = [1, 7, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    assert!(read_wkb(&buf).is_err());
+}
+
+#[test]
+fn corrupted_bytes_never_panic() {
+    for buf in geometry_collection_buffers() {
+        for i in 0..buf.len() {
+            for value in [0x00, 0x01, 0x07, 0x20, 0x80, 0xff] {
+                let mut corrupted = buf.clone();
+                corrupted[i] = value;
+                let result = read_wkb(&corrupted);
+                if let Ok(geom) = result {
+                    let _ = geom.to_geometry();
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn deeply_nested_collections_error() {
+    assert!(read_wkb(&nested_collections(MAX_NESTING_DEPTH)).is_ok());
+
+    let err = read_wkb(&nested_collections(MAX_NESTING_DEPTH + 1))
+        .err()
+        .unwrap();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert_eq!(err.offset(), Some(9 * (MAX_NESTING_DEPTH as u64 + 1)));
+    assert_eq!(err.path(), Some(&[0; MAX_NESTING_DEPTH + 1][..]));
+
+    // Far deeper nesting would overflow the stack without the limit
+    let err = read_wkb(&nested_collections(5000)).err().unwrap();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+}
+
+fn multi_polygon_buffer() -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_multi_polygon(&mut buf, &multi_polygon_2d(), Default::default()).unwrap();
+    buf
+}
+
+/// The offset of the second polygon within [`multi_polygon_buffer`]
+fn second_polygon_offset() -> usize {
+    9 + polygon_wkb_size(&multi_polygon_2d().0[0], Default::default())
+}
+
+#[test]
+fn unexpected_eof_location() {
+    let buf = multi_polygon_buffer();
+    let err = read_wkb(&buf[..buf.len() - 1]).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedEof { .. }));
+    // The interior ring of the second polygon is truncated
+    assert_eq!(err.path(), Some([1, 1].as_slice()));
+}
+
+#[test]
+fn invalid_byte_order_location() {
+    let mut buf = multi_polygon_buffer();
+    let offset = second_polygon_offset();
+    buf[offset] = 7;
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::InvalidByteOrder { byte: 7, .. }));
+    assert_eq!(err.offset(), Some(offset as u64));
+    assert_eq!(err.path(), Some([1].as_slice()));
+}
+
+#[test]
+fn unknown_geometry_code() {
+    let buf = [1, 99, 0, 0, 0, 0, 0, 0, 0];
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::UnknownGeometryCode {
+            code: 99,
+            offset: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn unsupported_dimension() {
+    // 4001 is not a valid ISO dimension
+    let mut buf = vec![1];
+    buf.extend_from_slice(&4001_u32.to_le_bytes());
+    buf.extend_from_slice(&[0; 16]);
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::UnsupportedDimension { code: 4001, .. }
+    ));
+}
+
+#[test]
+fn child_dimension_mismatch() {
+    let mut buf = multi_polygon_buffer();
+    let offset = second_polygon_offset();
+    buf[offset + 1..offset + 5].copy_from_slice(&1003_u32.to_le_bytes());
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::DimensionMismatch {
+            parent: WKBDimension::Xy,
+            child: WKBDimension::Xyz,
+            ..
+        }
+    ));
+    assert_eq!(err.offset(), Some(offset as u64));
+    assert_eq!(err.path(), Some([1].as_slice()));
+}
+
+#[test]
+fn child_unexpected_type() {
+    let mut buf = multi_polygon_buffer();
+    let offset = second_polygon_offset();
+    buf[offset + 1..offset + 5].copy_from_slice(&2_u32.to_le_bytes());
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedGeometryType { .. }));
+    assert_eq!(err.path(), Some([1].as_slice()));
+}
+
+#[test]
+fn nested_point_srid() {
+    // A little-endian MultiPoint with one Point that has SRID 4326
+    let mut buf = vec![1, 4, 0, 0, 0, 1, 0, 0, 0];
+    buf.extend_from_slice(&[1, 1, 0, 0, 0x20]);
+    buf.extend_from_slice(&4326_u32.to_le_bytes());
+    buf.extend_from_slice(&[0; 16]);
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedSrid { srid: 4326, .. }));
+    assert_eq!(err.offset(), Some(9));
+    assert_eq!(err.path(), Some([0].as_slice()));
+}
+
+#[test]
+fn trailing_bytes() {
+    let mut buf = multi_polygon_buffer();
+    let len = buf.len();
+    assert!(read_wkb_strict(&buf).is_ok());
+
+    buf.extend_from_slice(&[0, 1, 2]);
+    assert!(read_wkb(&buf).is_ok());
+
+    let err = read_wkb_strict(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::TrailingBytes { trailing: 3, .. }));
+    assert_eq!(err.offset(), Some(len as u64));
+
+    let (_, consumed) = read_wkb_prefix(&buf).unwrap();
+    assert_eq!(consumed, len);
+}
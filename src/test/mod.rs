@@ -1,3 +1,19 @@
+mod bounds;
+mod coords;
+mod curve;
 mod data;
 mod ewkb;
+mod gpkg;
+mod hex;
+mod info;
+mod lazy;
+mod malformed;
+mod mysql;
+mod options;
+mod spatialite;
+mod stream;
+mod surface;
+mod transcode;
+mod twkb;
+mod validate;
 mod wkb;
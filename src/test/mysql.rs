@@ -0,0 +1,115 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::{coord, polygon, Geometry, Point};
+
+use crate::error::WKBError;
+use crate::mysql::{mysql_geometry_size, read_mysql_geometry, write_mysql_geometry};
+use crate::writer::write_geometry;
+
+use super::data::*;
+
+/// Swap the x and y values of each coordinate of a Polygon
+fn swapped(polygon: &geo_types::Polygon) -> geo_types::Polygon {
+    let swap = |ring: &geo_types::LineString| {
+        ring.coords()
+            .map(|c| coord! { x: c.y, y: c.x })
+            .collect::<geo_types::LineString>()
+    };
+    geo_types::Polygon::new(
+        swap(polygon.exterior()),
+        polygon.interiors().iter().map(swap).collect(),
+    )
+}
+
+#[test]
+fn read_mysql_point() {
+    // SELECT ST_GeomFromText('POINT(1 2)', 3857)
+    let mut buf = 3857_u32.to_le_bytes().to_vec();
+    let point = Geometry::Point(Point::new(1., 2.));
+    write_geometry(&mut buf, &point, Default::default()).unwrap();
+
+    let geometry = read_mysql_geometry(&buf, false).unwrap();
+    assert_eq!(geometry.srid(), 3857);
+    assert_eq!(geometry.wkb(), &buf[4..]);
+    assert_eq!(geometry.geometry().to_geometry(), point);
+
+    let geometry = read_mysql_geometry(&buf, true).unwrap();
+    assert_eq!(
+        geometry.geometry().to_geometry(),
+        Geometry::Point(Point::new(2., 1.))
+    );
+}
+
+#[test]
+fn round_trip_swapped() {
+    let polygon = polygon_2d_with_interior();
+    let orig = Geometry::MultiPolygon(geo_types::MultiPolygon(vec![
+        polygon.clone(),
+        polygon![(x: 1., y: 2.), (x: 3., y: 4.), (x: 5., y: 2.)],
+    ]));
+
+    let mut buf = Vec::new();
+    write_mysql_geometry(&mut buf, &orig, 4326, true).unwrap();
+    assert_eq!(buf.len(), mysql_geometry_size(&orig));
+    assert_eq!(&buf[..4], &4326_u32.to_le_bytes());
+
+    // Without swapping back, the axes are in latitude-longitude order
+    let geometry = read_mysql_geometry(&buf, false).unwrap();
+    let Geometry::MultiPolygon(read) = geometry.geometry().to_geometry() else {
+        panic!("expected a MultiPolygon");
+    };
+    assert_eq!(read.0[0], swapped(&polygon));
+
+    let geometry = read_mysql_geometry(&buf, true).unwrap();
+    assert_eq!(geometry.geometry().to_geometry(), orig);
+
+    let collection = Geometry::GeometryCollection(geometry_collection_2d());
+    buf.clear();
+    write_mysql_geometry(&mut buf, &collection, 4326, true).unwrap();
+    let geometry = read_mysql_geometry(&buf, true).unwrap();
+    assert_eq!(geometry.geometry().to_geometry(), collection);
+}
+
+#[test]
+fn write_mysql_triangle() {
+    let triangle = Geometry::Triangle(geo_types::Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    ));
+    let mut buf = Vec::new();
+    write_mysql_geometry(&mut buf, &triangle, 4326, false).unwrap();
+    assert_eq!(buf.len(), mysql_geometry_size(&triangle));
+
+    // MySQL cannot read ISO WKB Triangles, so they are written as Polygons
+    assert_eq!(&buf[4..9], &[0x01, 0x03, 0x00, 0x00, 0x00]);
+    let geometry = read_mysql_geometry(&buf, false).unwrap();
+    let Geometry::Triangle(orig) = triangle else {
+        unreachable!()
+    };
+    assert_eq!(
+        geometry.geometry().to_geometry(),
+        Geometry::Polygon(orig.to_polygon())
+    );
+}
+
+#[test]
+fn invalid_mysql_geometry() {
+    let mut buf = Vec::new();
+    write_mysql_geometry(&mut buf, &polygon_2d(), 0, false).unwrap();
+    for len in 0..buf.len() {
+        assert!(read_mysql_geometry(&buf[..len], true).is_err());
+    }
+
+    // Offsets are from the start of the value
+    buf[4] = 2;
+    assert!(matches!(
+        read_mysql_geometry(&buf, false),
+        Err(WKBError::InvalidByteOrder { offset: 4, .. })
+    ));
+
+    let point_z: wkt::Wkt<f64> = "POINT Z (1 2 3)".parse().unwrap();
+    assert!(matches!(
+        write_mysql_geometry(&mut buf, &point_z, 0, false),
+        Err(WKBError::IncorrectType(_))
+    ));
+}
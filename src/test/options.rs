@@ -0,0 +1,111 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{GeometryCollectionTrait, MultiPointTrait, PointTrait};
+use geo_types::{coord, Geometry, Triangle};
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, Wkb};
+use crate::writer::{write_point, write_tin, EmptyPointEncoding, WriteOptions};
+use crate::{Endianness, WKBFlavor};
+
+use super::data::*;
+
+#[test]
+fn ogc11_drops_z_and_m() {
+    let options = WriteOptions {
+        flavor: WKBFlavor::Ogc11,
+        ..Default::default()
+    };
+    let geom: wkt::Wkt<f64> =
+        "GEOMETRYCOLLECTION ZM (POINT ZM (1 2 3 4), LINESTRING ZM (1 2 3 4, 5 6 7 8))"
+            .parse()
+            .unwrap();
+    let expected: wkt::Wkt<f64> = "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (1 2, 5 6))"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        to_wkb(&geom, options),
+        to_wkb(&expected, Default::default())
+    );
+
+    let polygon = Geometry::Polygon(polygon_2d_with_interior());
+    assert_eq!(
+        to_wkb(&polygon, options),
+        to_wkb(&polygon, Default::default())
+    );
+}
+
+#[test]
+fn ogc11_types() {
+    let options = WriteOptions {
+        flavor: WKBFlavor::Ogc11,
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    let triangle = Geometry::Triangle(Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    ));
+    let buf = to_wkb(&triangle, options);
+    assert_eq!(buf[..5], [0x00, 0x00, 0x00, 0x00, 0x03]);
+
+    // OGC 1.1 WKB has no TIN type
+    let tin = geo_types::MultiPolygon::<f64>::new(vec![]);
+    let err = write_tin(&mut Vec::new(), &tin, options).unwrap_err();
+    assert!(matches!(err, WKBError::IncorrectType(_)));
+}
+
+#[test]
+fn triangle_as_polygon() {
+    let orig = Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    );
+    let triangle = Geometry::Triangle(orig);
+    let buf = to_wkb(&triangle, Default::default());
+    assert!(matches!(read_wkb(&buf).unwrap(), Wkb::Triangle(_)));
+
+    let options = WriteOptions {
+        triangle_as_polygon: true,
+        ..Default::default()
+    };
+    let buf = to_wkb(&triangle, options);
+    let retour = read_wkb(&buf).unwrap();
+    assert!(matches!(retour, Wkb::Polygon(_)));
+    assert_eq!(retour.to_geometry(), Geometry::Polygon(orig.to_polygon()));
+}
+
+#[test]
+fn empty_point_encoding() {
+    let point: wkt::Wkt<f64> = "POINT EMPTY".parse().unwrap();
+
+    let buf = to_wkb(&point, Default::default());
+    let retour = read_wkb(&buf).unwrap();
+    assert!(matches!(&retour, Wkb::Point(p) if p.coord().is_none()));
+
+    let options = WriteOptions {
+        empty_point: EmptyPointEncoding::EmptyMultiPoint,
+        ..Default::default()
+    };
+    let buf = to_wkb(&point, options);
+    assert_eq!(buf, [0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+    let mut written = Vec::new();
+    let wkt::Wkt::Point(point) = &point else {
+        unreachable!()
+    };
+    write_point(&mut written, point, options).unwrap();
+    assert_eq!(buf, written);
+
+    // Nested empty points are written as empty MultiPoints too
+    let collection: wkt::Wkt<f64> = "GEOMETRYCOLLECTION (POINT EMPTY)".parse().unwrap();
+    let buf = to_wkb(&collection, options);
+    let Wkb::GeometryCollection(retour) = read_wkb(&buf).unwrap() else {
+        panic!("expected a GeometryCollection")
+    };
+    let Wkb::MultiPoint(multi_point) = retour.geometry(0).unwrap() else {
+        panic!("expected a MultiPoint")
+    };
+    assert_eq!(multi_point.num_points(), 0);
+}
@@ -0,0 +1,167 @@
+use byteorder::{LittleEndian, WriteBytesExt};
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, LineStringTrait};
+use geo_types::{point, Geometry, GeometryCollection};
+
+use crate::error::WKBError;
+use crate::spatialite::{
+    read_spatialite_geometry, spatialite_geometry_size, write_spatialite_geometry,
+};
+use crate::{Endianness, WKBDimension};
+
+use super::data::*;
+
+/// POINT(1 2) with SRID 4326, as stored by SpatiaLite
+fn point_blob() -> Vec<u8> {
+    let mut blob = vec![0x00, 0x01];
+    blob.write_i32::<LittleEndian>(4326).unwrap();
+    for value in [1., 2., 1., 2.] {
+        blob.write_f64::<LittleEndian>(value).unwrap();
+    }
+    blob.push(0x7c);
+    blob.write_u32::<LittleEndian>(1).unwrap();
+    blob.write_f64::<LittleEndian>(1.).unwrap();
+    blob.write_f64::<LittleEndian>(2.).unwrap();
+    blob.push(0xfe);
+    blob
+}
+
+fn encode(geom: &Geometry, compressed: bool, endianness: Endianness) -> Vec<u8> {
+    let mut blob = Vec::new();
+    write_spatialite_geometry(&mut blob, geom, 4326, compressed, endianness).unwrap();
+    assert_eq!(
+        blob.len(),
+        spatialite_geometry_size(geom, compressed).unwrap()
+    );
+    blob
+}
+
+#[test]
+fn read_point_blob() {
+    let blob = point_blob();
+    let geometry = read_spatialite_geometry(&blob).unwrap();
+    assert_eq!(geometry.srid(), 4326);
+    assert_eq!(geometry.mbr(), [1., 2., 1., 2.]);
+    let point = Geometry::Point(point!(x: 1., y: 2.));
+    assert_eq!(geometry.geometry().to_geometry(), point);
+
+    assert_eq!(encode(&point, false, Endianness::LittleEndian), blob);
+}
+
+#[test]
+fn round_trip_geometries() {
+    let geometries = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::Polygon(polygon_2d_with_interior()),
+        Geometry::MultiPoint(multi_point_2d()),
+        Geometry::MultiLineString(multi_line_string_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+        Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point_2d()),
+            Geometry::LineString(linestring_2d()),
+            Geometry::Polygon(polygon_2d_with_interior()),
+        ])),
+    ];
+    for geom in geometries {
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            for compressed in [false, true] {
+                let blob = encode(&geom, compressed, endianness);
+                let retour = read_spatialite_geometry(&blob).unwrap();
+                assert_eq!(retour.geometry().to_geometry(), geom);
+            }
+        }
+    }
+
+    let blob = encode(
+        &Geometry::MultiPolygon(multi_polygon_2d()),
+        false,
+        Endianness::BigEndian,
+    );
+    let geometry = read_spatialite_geometry(&blob).unwrap();
+    assert_eq!(geometry.mbr(), [-111., 41., -104., 45.]);
+}
+
+#[test]
+fn compressed_line_string_zm() {
+    let geom: wkt::Wkt<f64> = "LINESTRING ZM (0 0 0 0, 1.1 2.2 3.3 4.4, 5 6 7 8)"
+        .parse()
+        .unwrap();
+    let mut compressed = Vec::new();
+    write_spatialite_geometry(&mut compressed, &geom, 0, true, Endianness::LittleEndian).unwrap();
+    let mut uncompressed = Vec::new();
+    write_spatialite_geometry(&mut uncompressed, &geom, 0, false, Endianness::LittleEndian)
+        .unwrap();
+    // The middle point stores x, y and z as f32
+    assert_eq!(uncompressed.len() - compressed.len(), 12);
+    assert_eq!(&compressed[39..43], &1_003_002_u32.to_le_bytes());
+
+    let geometry = read_spatialite_geometry(&compressed).unwrap();
+    let wkb = geometry.geometry();
+    assert_eq!(wkb.dimension(), WKBDimension::Xyzm);
+    let GeometryType::LineString(line_string) = wkb.as_type() else {
+        panic!("expected a LineString");
+    };
+    let middle = line_string.coord(1).unwrap();
+    for (n, expected) in [1.1, 2.2, 3.3].into_iter().enumerate() {
+        approx::assert_relative_eq!(middle.nth_or_panic(n), expected, epsilon = 1e-6);
+    }
+    // m values are stored in full, as are the first and last points
+    assert_eq!(middle.nth_or_panic(3), 4.4);
+    let last = line_string.coord(2).unwrap();
+    assert_eq!((last.x(), last.y()), (5., 6.));
+}
+
+#[test]
+fn malformed_blob() {
+    let blob = point_blob();
+    for len in 0..blob.len() {
+        assert!(read_spatialite_geometry(&blob[..len]).is_err());
+    }
+
+    for (offset, byte) in [(0, 0x01), (38, 0x00), (59, 0x00)] {
+        let mut bad = blob.clone();
+        bad[offset] = byte;
+        let err = read_spatialite_geometry(&bad).err().unwrap();
+        assert!(matches!(err, WKBError::InvalidHeader { .. }));
+        assert_eq!(err.offset(), Some(offset as u64));
+    }
+
+    // A Point cannot be compressed
+    let mut bad = blob.clone();
+    bad[39..43].copy_from_slice(&1_000_001_u32.to_le_bytes());
+    assert!(matches!(
+        read_spatialite_geometry(&bad),
+        Err(WKBError::UnknownGeometryCode { offset: 39, .. })
+    ));
+
+    // A MultiPoint cannot contain a LineString
+    let mut blob = encode(
+        &Geometry::MultiPoint(multi_point_2d()),
+        false,
+        Endianness::LittleEndian,
+    );
+    blob[48..52].copy_from_slice(&2_u32.to_le_bytes());
+    let err = read_spatialite_geometry(&blob).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedGeometryType { .. }));
+    assert_eq!(err.path(), Some([0].as_slice()));
+
+    // Collections cannot be nested
+    let nested = Geometry::GeometryCollection(geometry_collection_2d());
+    let mut buf = Vec::new();
+    assert!(
+        write_spatialite_geometry(&mut buf, &nested, 0, false, Endianness::LittleEndian).is_err()
+    );
+    // Collection members must have the dimension of the collection
+    let mixed: wkt::Wkt<f64> = "GEOMETRYCOLLECTION (POINT Z (1 2 3), POINT (1 2))"
+        .parse()
+        .unwrap();
+    assert!(matches!(
+        spatialite_geometry_size(&mixed, false),
+        Err(WKBError::IncorrectType(_))
+    ));
+    assert!(matches!(
+        write_spatialite_geometry(&mut buf, &mixed, 0, false, Endianness::LittleEndian),
+        Err(WKBError::IncorrectType(_))
+    ));
+}
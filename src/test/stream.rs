@@ -0,0 +1,130 @@
+use std::io::Read;
+
+use geo_traits::to_geo::ToGeoGeometry;
+
+use crate::error::WKBError;
+use crate::reader::{WKBFraming, WKBStreamReader};
+use crate::writer::{write_geometry, WriteOptions};
+use crate::Endianness;
+
+use super::data::*;
+
+fn geometries() -> Vec<geo_types::Geometry> {
+    vec![
+        geo_types::Geometry::Point(point_2d()),
+        geo_types::Geometry::Polygon(polygon_2d_with_interior()),
+        geo_types::Geometry::MultiPolygon(multi_polygon_2d()),
+        geo_types::Geometry::GeometryCollection(geometry_collection_2d()),
+    ]
+}
+
+fn stream(framing: WKBFraming) -> Vec<u8> {
+    let mut stream = Vec::new();
+    for (i, geom) in geometries().iter().enumerate() {
+        // Alternate byte order between geometries
+        let endianness = if i % 2 == 0 {
+            Endianness::LittleEndian
+        } else {
+            Endianness::BigEndian
+        };
+        let mut buf = Vec::new();
+        write_geometry(
+            &mut buf,
+            geom,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        match framing {
+            WKBFraming::LengthPrefixed(Endianness::LittleEndian) => {
+                stream.extend_from_slice(&(buf.len() as u32).to_le_bytes())
+            }
+            WKBFraming::LengthPrefixed(Endianness::BigEndian) => {
+                stream.extend_from_slice(&(buf.len() as u32).to_be_bytes())
+            }
+            WKBFraming::SelfDelimited => (),
+        }
+        stream.extend_from_slice(&buf);
+    }
+    stream
+}
+
+/// A reader that returns at most one byte per call
+struct OneByte<R>(R);
+
+impl<R: Read> Read for OneByte<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+fn read_all<R: Read>(reader: R, framing: WKBFraming) -> Vec<geo_types::Geometry> {
+    let mut reader = WKBStreamReader::new(reader, framing);
+    let mut geometries = Vec::new();
+    while let Some(geom) = reader.next_geometry().unwrap() {
+        geometries.push(geom.to_geometry());
+    }
+    geometries
+}
+
+#[test]
+fn read_stream() {
+    for framing in [
+        WKBFraming::SelfDelimited,
+        WKBFraming::LengthPrefixed(Endianness::LittleEndian),
+        WKBFraming::LengthPrefixed(Endianness::BigEndian),
+    ] {
+        let buf = stream(framing);
+        assert_eq!(read_all(buf.as_slice(), framing), geometries());
+        assert_eq!(read_all(OneByte(buf.as_slice()), framing), geometries());
+    }
+}
+
+#[test]
+fn read_many_small_geometries() {
+    // Enough Points to span several reads from the underlying reader
+    let points = (0..2000)
+        .map(|i| geo_types::Geometry::Point(geo_types::Point::new(i as f64, 1.)))
+        .collect::<Vec<_>>();
+    let mut buf = Vec::new();
+    for point in &points {
+        write_geometry(&mut buf, point, Default::default()).unwrap();
+    }
+    assert_eq!(read_all(buf.as_slice(), WKBFraming::SelfDelimited), points);
+}
+
+#[test]
+fn truncated_stream_errors() {
+    for framing in [
+        WKBFraming::SelfDelimited,
+        WKBFraming::LengthPrefixed(Endianness::LittleEndian),
+    ] {
+        let buf = stream(framing);
+        let mut reader = WKBStreamReader::new(&buf[..buf.len() - 1], framing);
+        for _ in 0..geometries().len() - 1 {
+            assert!(reader.next_geometry().unwrap().is_some());
+        }
+        assert!(matches!(
+            reader.next_geometry(),
+            Err(WKBError::UnexpectedEof { .. })
+        ));
+    }
+}
+
+#[test]
+fn length_prefix_mismatch_errors() {
+    let mut buf = stream(WKBFraming::LengthPrefixed(Endianness::LittleEndian));
+    // Claim that the first point is one byte longer than it is
+    buf[0] += 1;
+    let mut reader = WKBStreamReader::new(
+        buf.as_slice(),
+        WKBFraming::LengthPrefixed(Endianness::LittleEndian),
+    );
+    assert!(matches!(
+        reader.next_geometry(),
+        Err(WKBError::TrailingBytes { trailing: 1, .. })
+    ));
+}
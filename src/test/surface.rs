@@ -0,0 +1,173 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{GeometryTrait, GeometryType};
+use geo_types::{coord, Geometry, Triangle};
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, Wkb};
+use crate::writer::{
+    geometry_wkb_size, polyhedral_surface_wkb_size, tin_wkb_size, write_geometry,
+    write_polyhedral_surface, write_tin, write_triangle, WriteOptions,
+};
+use crate::{Endianness, WKBDimension};
+
+use super::data::*;
+
+fn triangle_2d() -> Triangle {
+    Triangle::new(
+        coord! { x: 0., y: 0. },
+        coord! { x: 1., y: 0. },
+        coord! { x: 0., y: 1. },
+    )
+}
+
+/// A TIN Z of two triangles forming a square
+fn tin_z() -> wkt::types::MultiPolygon<f64> {
+    let c = |x: f64, y: f64| wkt::types::Coord {
+        x,
+        y,
+        z: Some(x + y),
+        m: None,
+    };
+    let triangle = |coords: [wkt::types::Coord<f64>; 3]| {
+        let mut ring = coords.to_vec();
+        ring.push(coords[0].clone());
+        wkt::types::Polygon(vec![wkt::types::LineString(ring)])
+    };
+    wkt::types::MultiPolygon(vec![
+        triangle([c(0., 0.), c(1., 0.), c(0., 1.)]),
+        triangle([c(1., 0.), c(1., 1.), c(0., 1.)]),
+    ])
+}
+
+#[test]
+fn round_trip_triangle() {
+    let orig = Geometry::Triangle(triangle_2d());
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let mut buf = Vec::new();
+        write_geometry(
+            &mut buf,
+            &orig,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(buf.len(), geometry_wkb_size(&orig, Default::default()));
+
+        let retour = read_wkb(&buf).unwrap();
+        assert!(matches!(retour, Wkb::Triangle(_)));
+        assert_eq!(orig, retour.to_geometry());
+    }
+
+    // An ISO Triangle with a closed ring of four points
+    let mut buf = Vec::new();
+    write_triangle(&mut buf, &triangle_2d(), Default::default()).unwrap();
+    assert_eq!(&buf[1..5], &17_u32.to_le_bytes());
+    assert_eq!(&buf[5..9], &1_u32.to_le_bytes());
+    assert_eq!(&buf[9..13], &4_u32.to_le_bytes());
+    assert_eq!(&buf[13..29], &buf[61..77]);
+}
+
+#[test]
+fn read_empty_triangle() {
+    let mut buf = vec![1];
+    buf.extend_from_slice(&17_u32.to_le_bytes());
+    buf.extend_from_slice(&0_u32.to_le_bytes());
+
+    let geom = read_wkb(&buf).unwrap();
+    assert!(matches!(&geom, Wkb::Triangle(tri) if tri.is_empty()));
+    assert!(matches!(geom.as_type(), GeometryType::Polygon(_)));
+}
+
+#[test]
+fn invalid_triangle() {
+    // A Triangle with the interior ring of a Polygon
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &polygon_2d_with_interior(), Default::default()).unwrap();
+    buf[1..5].copy_from_slice(&17_u32.to_le_bytes());
+
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::InvalidTriangle { num_rings: 2, .. }
+    ));
+    // A Triangle whose fourth point differs from its first
+    let mut buf = Vec::new();
+    write_triangle(&mut buf, &triangle_2d(), Default::default()).unwrap();
+    let last_x = buf.len() - 16;
+    buf[last_x..last_x + 8].copy_from_slice(&5_f64.to_le_bytes());
+
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(
+        err,
+        WKBError::InvalidTriangle {
+            num_rings: 1,
+            num_points: 4,
+            closed: false,
+            offset: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn round_trip_polyhedral_surface() {
+    let orig = multi_polygon_2d();
+    let mut buf = Vec::new();
+    write_polyhedral_surface(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(&buf[1..5], &15_u32.to_be_bytes());
+    assert_eq!(
+        buf.len(),
+        polyhedral_surface_wkb_size(&orig, Default::default())
+    );
+
+    let retour = read_wkb(&buf).unwrap();
+    assert!(matches!(retour, Wkb::PolyhedralSurface(_)));
+    assert_eq!(retour.size(), buf.len() as u64);
+    assert_eq!(Geometry::MultiPolygon(orig), retour.to_geometry());
+}
+
+#[test]
+fn round_trip_tin_z() {
+    let orig = tin_z();
+    let mut buf = Vec::new();
+    write_tin(&mut buf, &orig, Default::default()).unwrap();
+    assert_eq!(&buf[1..5], &1016_u32.to_le_bytes());
+    assert_eq!(buf.len(), tin_wkb_size(&orig, Default::default()));
+
+    let retour = read_wkb(&buf).unwrap();
+    assert!(matches!(retour, Wkb::Tin(_)));
+    assert_eq!(retour.dimension(), WKBDimension::Xyz);
+    let GeometryType::MultiPolygon(patches) = retour.as_type() else {
+        panic!("expected a MultiPolygon");
+    };
+
+    // Writing the patches back out as a TIN is lossless
+    let mut copy = Vec::new();
+    write_tin(&mut copy, patches, Default::default()).unwrap();
+    assert_eq!(buf, copy);
+}
+
+#[test]
+fn tin_parts_must_be_triangles() {
+    // Each patch of a TIN must be a Triangle, not a Polygon
+    let mut buf = Vec::new();
+    write_polyhedral_surface(&mut buf, &tin_z(), Default::default()).unwrap();
+    buf[1..5].copy_from_slice(&1016_u32.to_le_bytes());
+    let err = read_wkb(&buf).err().unwrap();
+    assert!(matches!(err, WKBError::UnexpectedGeometryType { .. }));
+    assert_eq!(err.path(), Some([0].as_slice()));
+
+    // Polygons that are not triangles cannot be written as a TIN
+    let mut buf = Vec::new();
+    assert!(write_tin(&mut buf, &multi_polygon_2d(), Default::default()).is_err());
+}
@@ -0,0 +1,305 @@
+use geo_traits::PointTrait;
+use geo_types::Geometry;
+
+use crate::error::WKBError;
+use crate::reader::{read_wkb, Wkb, MAX_NESTING_DEPTH};
+use crate::transcode::{
+    convert_byte_order, convert_byte_order_in_place, convert_dimension, convert_to_ewkb,
+    convert_to_iso, DimensionChange, DimensionOptions, SridChange,
+};
+use crate::writer::{write_circular_string, write_point, WriteOptions};
+use crate::{Endianness, WKBDimension, WKBFlavor};
+
+use super::data::*;
+
+#[test]
+fn convert_byte_order_matches_writer() {
+    let little = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    let big = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..little
+    };
+    let geom = Geometry::GeometryCollection(geometry_collection_2d());
+    let little_buf = to_wkb(&geom, little);
+    let big_buf = to_wkb(&geom, big);
+
+    assert_eq!(
+        convert_byte_order(&little_buf, Endianness::BigEndian).unwrap(),
+        big_buf
+    );
+    assert_eq!(
+        convert_byte_order(&big_buf, Endianness::LittleEndian).unwrap(),
+        little_buf
+    );
+    // Converting to the existing byte order is a no-op
+    assert_eq!(
+        convert_byte_order(&big_buf, Endianness::BigEndian).unwrap(),
+        big_buf
+    );
+
+    let mut buf = little_buf.clone();
+    buf.extend_from_slice(&[0xff, 0xff]);
+    let size = convert_byte_order_in_place(&mut buf, Endianness::BigEndian).unwrap();
+    assert_eq!(size, big_buf.len());
+    assert_eq!(buf[..size], big_buf);
+    assert_eq!(buf[size..], [0xff, 0xff]);
+}
+
+#[test]
+fn convert_mixed_byte_orders() {
+    let big = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    let point: wkt::Wkt<f64> = "POINT ZM (1 2 3 4)".parse().unwrap();
+    let wkt::Wkt::Point(point) = point else {
+        unreachable!()
+    };
+
+    // A little-endian MultiPoint with a big-endian Point and a little-endian Point
+    let mut buf = vec![0x01, 0xbc, 0x0b, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+    write_point(&mut buf, &point, big).unwrap();
+    write_point(&mut buf, &point, Default::default()).unwrap();
+
+    let multi_point: wkt::Wkt<f64> = "MULTIPOINT ZM ((1 2 3 4), (1 2 3 4))".parse().unwrap();
+    assert_eq!(
+        convert_byte_order(&buf, Endianness::LittleEndian).unwrap(),
+        to_wkb(&multi_point, Default::default())
+    );
+    assert_eq!(
+        convert_byte_order(&buf, Endianness::BigEndian).unwrap(),
+        to_wkb(&multi_point, big)
+    );
+}
+
+#[test]
+fn convert_curve_byte_order() {
+    let geom = geo_types::LineString::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+    let mut little_buf = Vec::new();
+    write_circular_string(&mut little_buf, &geom, Default::default()).unwrap();
+    let mut big_buf = Vec::new();
+    let big = WriteOptions {
+        endianness: Endianness::BigEndian,
+        ..Default::default()
+    };
+    write_circular_string(&mut big_buf, &geom, big).unwrap();
+    assert_eq!(
+        convert_byte_order(&little_buf, Endianness::BigEndian).unwrap(),
+        big_buf
+    );
+}
+
+#[test]
+fn convert_truncated_unchanged() {
+    let buf = to_wkb(
+        &Geometry::MultiPolygon(multi_polygon_2d()),
+        Default::default(),
+    );
+    let mut truncated = buf[..buf.len() - 1].to_vec();
+    let err = convert_byte_order_in_place(&mut truncated, Endianness::BigEndian).unwrap_err();
+    assert!(matches!(err, WKBError::UnexpectedEof { .. }));
+    assert_eq!(err.path(), Some(&[1, 1][..]));
+    assert_eq!(truncated, buf[..buf.len() - 1]);
+}
+
+#[test]
+fn convert_byte_order_deeply_nested() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    let converted = convert_byte_order(&buf, Endianness::BigEndian).unwrap();
+    assert_eq!(
+        convert_byte_order(&converted, Endianness::LittleEndian).unwrap(),
+        buf
+    );
+
+    let mut buf = nested_collections(5000);
+    let orig = buf.clone();
+    let err = convert_byte_order_in_place(&mut buf, Endianness::BigEndian).unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert_eq!(err.path().unwrap().len(), MAX_NESTING_DEPTH + 1);
+    assert_eq!(buf, orig);
+}
+
+/// Check that converting `input` with `options` gives `expected`, both written with
+/// `write_options`
+fn check_convert_dimension(
+    input: &str,
+    options: DimensionOptions,
+    expected: &str,
+    write_options: WriteOptions,
+) {
+    let input: wkt::Wkt<f64> = input.parse().unwrap();
+    let expected: wkt::Wkt<f64> = expected.parse().unwrap();
+    assert_eq!(
+        convert_dimension(&to_wkb(&input, write_options), options).unwrap(),
+        to_wkb(&expected, write_options)
+    );
+}
+
+#[test]
+fn drop_dimensions() {
+    let drop_m = DimensionOptions {
+        m: DimensionChange::Drop,
+        ..Default::default()
+    };
+    let ewkb = WriteOptions {
+        endianness: Endianness::BigEndian,
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    for write_options in [WriteOptions::default(), ewkb] {
+        check_convert_dimension(
+            "GEOMETRYCOLLECTION ZM (POINT ZM (1 2 3 4), LINESTRING ZM (1 2 3 4, 5 6 7 8))",
+            drop_m,
+            "GEOMETRYCOLLECTION Z (POINT Z (1 2 3), LINESTRING Z (1 2 3, 5 6 7))",
+            write_options,
+        );
+        check_convert_dimension(
+            "MULTIPOLYGON M (((0 0 1, 1 0 2, 0 1 3, 0 0 1)))",
+            DimensionOptions::FORCE_2D,
+            "MULTIPOLYGON (((0 0, 1 0, 0 1, 0 0)))",
+            write_options,
+        );
+        check_convert_dimension(
+            "MULTIPOINT Z (1 2 3, 4 5 6)",
+            drop_m,
+            "MULTIPOINT Z (1 2 3, 4 5 6)",
+            write_options,
+        );
+    }
+}
+
+#[test]
+fn add_dimensions() {
+    let add_zm = DimensionOptions {
+        z: DimensionChange::Add(0.),
+        m: DimensionChange::Add(-1.),
+    };
+    check_convert_dimension(
+        "POLYGON ((0 0, 1 0, 0 1, 0 0), (0.1 0.1, 0.2 0.1, 0.1 0.2, 0.1 0.1))",
+        add_zm,
+        "POLYGON ZM ((0 0 0 -1, 1 0 0 -1, 0 1 0 -1, 0 0 0 -1), \
+         (0.1 0.1 0 -1, 0.2 0.1 0 -1, 0.1 0.2 0 -1, 0.1 0.1 0 -1))",
+        Default::default(),
+    );
+    // Existing values are kept
+    check_convert_dimension(
+        "LINESTRING M (1 2 3, 4 5 6)",
+        add_zm,
+        "LINESTRING ZM (1 2 0 3, 4 5 0 6)",
+        Default::default(),
+    );
+}
+
+#[test]
+fn add_dimensions_to_empty_point() {
+    let add_z = DimensionOptions {
+        z: DimensionChange::Add(0.),
+        ..Default::default()
+    };
+    let point: wkt::Wkt<f64> = "POINT EMPTY".parse().unwrap();
+    let buf = convert_dimension(&to_wkb(&point, Default::default()), add_z).unwrap();
+    let Wkb::Point(point) = read_wkb(&buf).unwrap() else {
+        panic!("expected a Point")
+    };
+    assert_eq!(point.dimension(), WKBDimension::Xyz);
+    assert!(point.coord().is_none());
+}
+
+#[test]
+fn convert_dimension_deeply_nested() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    let xyz = convert_dimension(
+        &buf,
+        DimensionOptions {
+            z: DimensionChange::Add(0.),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        convert_dimension(&xyz, DimensionOptions::FORCE_2D).unwrap(),
+        buf
+    );
+
+    let err = convert_dimension(&nested_collections(5000), DimensionOptions::FORCE_2D).unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+}
+
+#[test]
+fn convert_flavor() {
+    let geom: wkt::Wkt<f64> =
+        "GEOMETRYCOLLECTION ZM (POINT ZM (1 2 3 4), POLYGON ZM ((0 0 0 0, 1 0 0 0, 0 1 0 0, 0 0 0 0)))"
+            .parse()
+            .unwrap();
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let with_flavor = |flavor| WriteOptions {
+            endianness,
+            flavor,
+            ..Default::default()
+        };
+        let iso = to_wkb(&geom, with_flavor(WKBFlavor::Iso));
+        let ewkb = to_wkb(&geom, with_flavor(WKBFlavor::Extended { srid: None }));
+        let ewkb_4326 = to_wkb(&geom, with_flavor(WKBFlavor::Extended { srid: Some(4326) }));
+
+        assert_eq!(convert_to_iso(&ewkb_4326).unwrap(), iso);
+        assert_eq!(convert_to_iso(&iso).unwrap(), iso);
+        assert_eq!(convert_to_ewkb(&iso, SridChange::Keep).unwrap(), ewkb);
+        assert_eq!(
+            convert_to_ewkb(&iso, SridChange::Set(4326)).unwrap(),
+            ewkb_4326
+        );
+        assert_eq!(
+            convert_to_ewkb(&ewkb_4326, SridChange::Keep).unwrap(),
+            ewkb_4326
+        );
+        assert_eq!(
+            convert_to_ewkb(&ewkb_4326, SridChange::Strip).unwrap(),
+            ewkb
+        );
+
+        let ewkb_3857 = convert_to_ewkb(&ewkb_4326, SridChange::Set(3857)).unwrap();
+        assert_eq!(read_wkb(&ewkb_3857).unwrap().srid(), Some(3857));
+    }
+}
+
+#[test]
+fn convert_flavor_deeply_nested() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    let ewkb = convert_to_ewkb(&buf, SridChange::Set(4326)).unwrap();
+    assert_eq!(read_wkb(&ewkb).unwrap().srid(), Some(4326));
+    assert_eq!(convert_to_iso(&ewkb).unwrap(), buf);
+
+    let buf = nested_collections(5000);
+    assert!(matches!(
+        convert_to_iso(&buf).unwrap_err(),
+        WKBError::TooDeeplyNested { .. }
+    ));
+    assert!(matches!(
+        convert_to_ewkb(&buf, SridChange::Keep).unwrap_err(),
+        WKBError::TooDeeplyNested { .. }
+    ));
+}
+
+#[test]
+fn convert_flavor_drops_nested_srids() {
+    let ewkb = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    // A little-endian GeometryCollection with a Point that has its own SRID
+    let mut buf = vec![0x01, 0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+    write_point(&mut buf, &point_2d(), ewkb).unwrap();
+
+    let collection = Geometry::GeometryCollection(vec![Geometry::Point(point_2d())].into());
+    assert_eq!(
+        convert_to_ewkb(&buf, SridChange::Set(4326)).unwrap(),
+        to_wkb(&collection, ewkb)
+    );
+    assert_eq!(
+        convert_to_iso(&buf).unwrap(),
+        to_wkb(&collection, Default::default())
+    );
+}
@@ -0,0 +1,173 @@
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::{point, Geometry, LineString, MultiPoint};
+
+use crate::error::WKBError;
+use crate::reader::{Wkb, MAX_NESTING_DEPTH};
+use crate::twkb::{read_twkb, write_twkb, write_twkb_with_ids, TwkbOptions};
+use crate::WKBDimension;
+
+use super::data::*;
+
+fn encode(geom: &Geometry, options: TwkbOptions) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_twkb(&mut buf, geom, options).unwrap();
+    buf
+}
+
+fn round_trip(geom: Geometry, options: TwkbOptions) {
+    let buf = encode(&geom, options);
+    let twkb = read_twkb(&buf).unwrap();
+    assert_eq!(twkb.twkb_size(), buf.len());
+    assert_eq!(twkb.geometry().to_geometry(), geom);
+}
+
+#[test]
+fn spec_line_string() {
+    // SELECT ST_AsTWKB('LINESTRING(1 1, 5 5)')
+    let expected = [0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08];
+    let geom = Geometry::LineString(LineString::from(vec![(1., 1.), (5., 5.)]));
+    assert_eq!(encode(&geom, Default::default()), expected);
+    assert_eq!(read_twkb(&expected).unwrap().geometry().to_geometry(), geom);
+}
+
+#[test]
+fn spec_multi_point_with_ids() {
+    // SELECT ST_AsTWKB(ARRAY['POINT(0 0)', 'POINT(1 1)'], ARRAY[1, 2])
+    let expected = [0x04, 0x04, 0x02, 0x02, 0x04, 0x00, 0x00, 0x02, 0x02];
+    let geom = Geometry::MultiPoint(MultiPoint::from(vec![(0., 0.), (1., 1.)]));
+    let mut buf = Vec::new();
+    write_twkb_with_ids(&mut buf, &geom, &[1, 2], Default::default()).unwrap();
+    assert_eq!(buf, expected);
+
+    let twkb = read_twkb(&expected).unwrap();
+    assert_eq!(twkb.ids(), Some([1, 2].as_slice()));
+    assert_eq!(twkb.geometry().to_geometry(), geom);
+
+    // Ids must match the parts
+    assert!(write_twkb_with_ids(&mut buf, &geom, &[1], Default::default()).is_err());
+    let point = Geometry::Point(point!(x: 0., y: 0.));
+    assert!(write_twkb_with_ids(&mut buf, &point, &[1], Default::default()).is_err());
+}
+
+#[test]
+fn bbox_and_size() {
+    let options = TwkbOptions {
+        xy_precision: 2,
+        bbox: true,
+        size: true,
+        ..Default::default()
+    };
+    let geom = Geometry::Point(point!(x: 1.5, y: -2.25));
+    let expected = [
+        0x41, // Point, precision 2
+        0x03, // bbox and size
+        0x0a, // size
+        0xac, 0x02, 0x00, 0xc1, 0x03, 0x00, // bbox
+        0xac, 0x02, 0xc1, 0x03, // 150, -225
+    ];
+    assert_eq!(encode(&geom, options), expected);
+    round_trip(geom, options);
+}
+
+#[test]
+fn extended_dimensions() {
+    let options = TwkbOptions {
+        z_precision: 1,
+        ..Default::default()
+    };
+    let geom: wkt::Wkt<f64> = "LINESTRING Z (1 2 3, 2 4 3.5)".parse().unwrap();
+    let mut buf = Vec::new();
+    write_twkb(&mut buf, &geom, options).unwrap();
+    assert_eq!(
+        buf,
+        [0x02, 0x08, 0x05, 0x02, 0x02, 0x04, 0x3c, 0x02, 0x04, 0x0a]
+    );
+
+    let twkb = read_twkb(&buf).unwrap();
+    assert!(matches!(twkb.geometry(), Wkb::LineString(_)));
+    assert_eq!(twkb.geometry().dimension(), WKBDimension::Xyz);
+}
+
+#[test]
+fn empty_geometries() {
+    let geom = Geometry::LineString(LineString::new(vec![]));
+    assert_eq!(encode(&geom, Default::default()), [0x02, 0x10]);
+    round_trip(geom, Default::default());
+
+    let twkb = read_twkb(&[0x01, 0x10]).unwrap();
+    assert!(matches!(twkb.geometry(), Wkb::Point(_)));
+}
+
+#[test]
+fn round_trip_geometries() {
+    let options = TwkbOptions {
+        bbox: true,
+        size: true,
+        ..Default::default()
+    };
+    round_trip(Geometry::Polygon(polygon_2d_with_interior()), options);
+    round_trip(Geometry::MultiLineString(multi_line_string_2d()), options);
+    round_trip(Geometry::MultiPolygon(multi_polygon_2d()), options);
+    round_trip(
+        Geometry::GeometryCollection(geometry_collection_2d()),
+        options,
+    );
+
+    // Coordinates are rounded to the precision
+    let options = TwkbOptions {
+        xy_precision: -1,
+        ..Default::default()
+    };
+    let buf = encode(&Geometry::Point(point!(x: 14., y: 26.)), options);
+    let twkb = read_twkb(&buf).unwrap();
+    assert_eq!(
+        twkb.geometry().to_geometry(),
+        Geometry::Point(point!(x: 10., y: 30.))
+    );
+}
+
+#[test]
+fn malformed_twkb() {
+    let buf = encode(
+        &Geometry::MultiPolygon(multi_polygon_2d()),
+        Default::default(),
+    );
+    for len in 0..buf.len() {
+        assert!(read_twkb(&buf[..len]).is_err());
+    }
+
+    assert!(matches!(
+        read_twkb(&[0x08, 0x00]),
+        Err(WKBError::UnknownGeometryCode { code: 8, .. })
+    ));
+
+    let mut overflow = vec![0x01, 0x00];
+    overflow.extend_from_slice(&[0xff; 10]);
+    assert!(matches!(
+        read_twkb(&overflow),
+        Err(WKBError::InvalidVarint { offset: 2, .. })
+    ));
+
+    // A huge point count fails without allocating
+    assert!(matches!(
+        read_twkb(&[0x02, 0x00, 0xff, 0xff, 0xff, 0xff, 0x0f]),
+        Err(WKBError::UnexpectedEof { .. })
+    ));
+}
+
+#[test]
+fn deeply_nested_twkb() {
+    // A Point nested in `depth` GeometryCollections of one geometry each
+    let nested = |depth: usize| {
+        let mut buf = [0x07, 0x00, 0x01].repeat(depth);
+        buf.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        buf
+    };
+    let twkb = read_twkb(&nested(MAX_NESTING_DEPTH)).unwrap();
+    assert!(matches!(twkb.geometry(), Wkb::GeometryCollection(_)));
+
+    let err = read_twkb(&nested(5000)).unwrap_err();
+    assert!(matches!(err, WKBError::TooDeeplyNested { .. }));
+    assert_eq!(err.offset(), Some(3 * (MAX_NESTING_DEPTH as u64 + 1)));
+    assert_eq!(err.path().unwrap().len(), MAX_NESTING_DEPTH + 1);
+}
@@ -0,0 +1,192 @@
+use geo_types::{Geometry, LineString, Point};
+
+use crate::error::WKBError;
+use crate::reader::MAX_NESTING_DEPTH;
+use crate::validate::{validate_wkb, IssueKind, Strictness, ValidationIssue};
+use crate::writer::WriteOptions;
+use crate::{WKBDimension, WKBFlavor, WKBType};
+
+use super::data::*;
+
+fn wkt_to_wkb(wkt: &str) -> Vec<u8> {
+    let geom: wkt::Wkt<f64> = wkt.parse().unwrap();
+    to_wkb(&geom, Default::default())
+}
+
+/// A little-endian geometry with the given code containing each of `parts`
+fn collection(code: u32, parts: &[Vec<u8>]) -> Vec<u8> {
+    let mut buf = vec![1];
+    buf.extend_from_slice(&code.to_le_bytes());
+    buf.extend_from_slice(&(parts.len() as u32).to_le_bytes());
+    for part in parts {
+        buf.extend_from_slice(part);
+    }
+    buf
+}
+
+fn issue(
+    kind: IssueKind,
+    path: &[usize],
+    ring: Option<usize>,
+    vertex: Option<usize>,
+) -> ValidationIssue {
+    ValidationIssue {
+        kind,
+        path: path.to_vec(),
+        ring,
+        vertex,
+    }
+}
+
+#[test]
+fn valid_geometries() {
+    let geom = Geometry::GeometryCollection(geometry_collection_2d());
+    let buf = to_wkb(&geom, Default::default());
+    assert_eq!(validate_wkb(&buf, Strictness::Strict).unwrap(), []);
+
+    let buf = wkt_to_wkb("GEOMETRYCOLLECTION (POINT EMPTY, LINESTRING EMPTY, POLYGON EMPTY)");
+    assert_eq!(validate_wkb(&buf, Strictness::Strict).unwrap(), []);
+}
+
+#[test]
+fn invalid_rings() {
+    let buf = wkt_to_wkb("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((0 0, 1 0, 0 1)))");
+    assert_eq!(
+        validate_wkb(&buf, Strictness::Strict).unwrap(),
+        [
+            issue(
+                IssueKind::TooFewPoints { found: 3, min: 4 },
+                &[1],
+                Some(0),
+                None
+            ),
+            issue(IssueKind::UnclosedRing, &[1], Some(0), None),
+        ]
+    );
+    assert_eq!(validate_wkb(&buf, Strictness::Lenient).unwrap(), []);
+}
+
+#[test]
+fn invalid_triangle_rings() {
+    // A Triangle is a Polygon with code 17
+    let triangle = |wkt| {
+        let mut buf = wkt_to_wkb(wkt);
+        buf[1..5].copy_from_slice(&17_u32.to_le_bytes());
+        buf
+    };
+    let buf = triangle("POLYGON ((0 0, 1 0, 0 1, 0 0))");
+    assert_eq!(validate_wkb(&buf, Strictness::Strict).unwrap(), []);
+
+    let buf = triangle("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))");
+    assert_eq!(
+        validate_wkb(&buf, Strictness::Strict).unwrap(),
+        [issue(
+            IssueKind::TooManyPoints { found: 5, max: 4 },
+            &[],
+            Some(0),
+            None
+        )]
+    );
+    assert_eq!(validate_wkb(&buf, Strictness::Lenient).unwrap(), []);
+}
+
+#[test]
+fn nan_coordinates() {
+    let line_string = LineString::from(vec![(0., 0.), (f64::NAN, 1.)]);
+    let buf = to_wkb(&line_string, Default::default());
+    assert_eq!(
+        validate_wkb(&buf, Strictness::Strict).unwrap(),
+        [issue(IssueKind::NanCoordinate, &[], None, Some(1))]
+    );
+
+    let point = Point::new(f64::NAN, 1.);
+    let buf = to_wkb(&point, Default::default());
+    assert_eq!(
+        validate_wkb(&buf, Strictness::Strict).unwrap(),
+        [issue(IssueKind::NanCoordinate, &[], None, Some(0))]
+    );
+}
+
+#[test]
+fn nan_closing_coordinate() {
+    // The ring is reported as having a NaN coordinate, but not as unclosed
+    let mut buf = wkt_to_wkb("POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    let last = 1 + 4 + 4 + 4 + 3 * 16;
+    buf[last..last + 8].copy_from_slice(&f64::NAN.to_le_bytes());
+    assert_eq!(
+        validate_wkb(&buf, Strictness::Strict).unwrap(),
+        [issue(IssueKind::NanCoordinate, &[], Some(0), Some(3))]
+    );
+}
+
+#[test]
+fn invalid_nesting() {
+    let ewkb = WriteOptions {
+        flavor: WKBFlavor::Extended { srid: Some(4326) },
+        ..Default::default()
+    };
+    let point_z = wkt_to_wkb("POINT Z (1 2 3)");
+    let point_with_srid = to_wkb(&point_2d(), ewkb);
+    let line_string = wkt_to_wkb("LINESTRING (0 0, 1 1)");
+
+    let buf = collection(
+        7,
+        &[
+            point_z,
+            collection(6, &[line_string]),
+            collection(4, &[point_with_srid]),
+        ],
+    );
+    let expected = [
+        issue(
+            IssueKind::DimensionMismatch {
+                parent: WKBDimension::Xy,
+                child: WKBDimension::Xyz,
+            },
+            &[0],
+            None,
+            None,
+        ),
+        issue(
+            IssueKind::UnexpectedPartType {
+                expected: WKBType::Polygon(WKBDimension::Xy),
+                found: WKBType::LineString(WKBDimension::Xy),
+            },
+            &[1, 0],
+            None,
+            None,
+        ),
+        issue(IssueKind::NestedSrid { srid: 4326 }, &[2, 0], None, None),
+    ];
+    assert_eq!(validate_wkb(&buf, Strictness::Lenient).unwrap(), expected);
+    assert_eq!(validate_wkb(&buf, Strictness::Strict).unwrap(), expected);
+    assert_eq!(
+        expected[1].to_string(),
+        "expected part of type Polygon(Xy) but found LineString(Xy) (path [1, 0])"
+    );
+}
+
+#[test]
+fn malformed_errors() {
+    let buf = wkt_to_wkb("POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    for len in 0..buf.len() {
+        assert!(validate_wkb(&buf[..len], Strictness::Strict).is_err());
+    }
+}
+
+#[test]
+fn deeply_nested_errors() {
+    let buf = nested_collections(MAX_NESTING_DEPTH);
+    assert!(validate_wkb(&buf, Strictness::Strict).unwrap().is_empty());
+
+    for depth in [MAX_NESTING_DEPTH + 1, 5000] {
+        let err = validate_wkb(&nested_collections(depth), Strictness::Strict).unwrap_err();
+        match err {
+            WKBError::TooDeeplyNested { offset, path } => {
+                assert_eq!(offset, 9 * (MAX_NESTING_DEPTH as u64 + 1));
+                assert_eq!(path, vec![0; MAX_NESTING_DEPTH + 1]);
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+}
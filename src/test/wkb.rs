@@ -1,12 +1,12 @@
 use geo_traits::to_geo::ToGeoGeometry;
 use geo_types::Geometry;
 
-use crate::reader::read_wkb;
+use crate::reader::{read_wkb, Wkb};
 use crate::writer::{
-    write_geometry_collection, write_line_string, write_multi_line_string, write_multi_point,
-    write_multi_polygon, write_point, write_polygon,
+    write_geometry, write_geometry_collection, write_line_string, write_multi_line_string,
+    write_multi_point, write_multi_polygon, write_point, write_polygon, WriteOptions,
 };
-use crate::Endianness;
+use crate::{Endianness, WKBDimension};
 
 use super::data::*;
 
@@ -14,13 +14,21 @@ use super::data::*;
 fn round_trip_point() {
     let orig = point_2d();
     let mut buf = Vec::new();
-    write_point(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_point(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Point(orig), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_point(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_point(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Point(orig), retour.to_geometry());
 }
@@ -30,13 +38,21 @@ fn round_trip_line_string() {
     let orig = linestring_2d();
 
     let mut buf = Vec::new();
-    write_line_string(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_line_string(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::LineString(orig.clone()), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_line_string(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_line_string(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::LineString(orig), retour.to_geometry());
 }
@@ -46,13 +62,21 @@ fn round_trip_polygon() {
     let orig = polygon_2d();
 
     let mut buf = Vec::new();
-    write_polygon(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_polygon(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_polygon(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_polygon(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Polygon(orig), retour.to_geometry());
 }
@@ -62,13 +86,21 @@ fn round_trip_polygon_with_interior() {
     let orig = polygon_2d_with_interior();
 
     let mut buf = Vec::new();
-    write_polygon(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_polygon(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Polygon(orig.clone()), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_polygon(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_polygon(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::Polygon(orig), retour.to_geometry());
 }
@@ -78,13 +110,21 @@ fn round_trip_multi_point() {
     let orig = multi_point_2d();
 
     let mut buf = Vec::new();
-    write_multi_point(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_multi_point(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::MultiPoint(orig.clone()), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_multi_point(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_multi_point(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::MultiPoint(orig), retour.to_geometry());
 }
@@ -94,7 +134,7 @@ fn round_trip_multi_line_string() {
     let orig = multi_line_string_2d();
 
     let mut buf = Vec::new();
-    write_multi_line_string(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_multi_line_string(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(
         Geometry::MultiLineString(orig.clone()),
@@ -103,7 +143,15 @@ fn round_trip_multi_line_string() {
 
     // Big endian
     let mut buf = Vec::new();
-    write_multi_line_string(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_multi_line_string(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::MultiLineString(orig), retour.to_geometry());
 }
@@ -113,13 +161,21 @@ fn round_trip_multi_polygon() {
     let orig = multi_polygon_2d();
 
     let mut buf = Vec::new();
-    write_multi_polygon(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_multi_polygon(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::MultiPolygon(orig.clone()), retour.to_geometry());
 
     // Big endian
     let mut buf = Vec::new();
-    write_multi_polygon(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_multi_polygon(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::MultiPolygon(orig), retour.to_geometry());
 }
@@ -129,7 +185,7 @@ fn round_trip_geometry_collection() {
     let orig = geometry_collection_2d();
 
     let mut buf = Vec::new();
-    write_geometry_collection(&mut buf, &orig, Endianness::LittleEndian).unwrap();
+    write_geometry_collection(&mut buf, &orig, Default::default()).unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(
         Geometry::GeometryCollection(orig.clone()),
@@ -138,7 +194,172 @@ fn round_trip_geometry_collection() {
 
     // Big endian
     let mut buf = Vec::new();
-    write_geometry_collection(&mut buf, &orig, Endianness::BigEndian).unwrap();
+    write_geometry_collection(
+        &mut buf,
+        &orig,
+        WriteOptions {
+            endianness: Endianness::BigEndian,
+            ..Default::default()
+        },
+    )
+    .unwrap();
     let retour = read_wkb(&buf).unwrap();
     assert_eq!(Geometry::GeometryCollection(orig), retour.to_geometry());
 }
+
+#[test]
+fn store_parsed_geometries() {
+    let buffers = [
+        Geometry::Point(point_2d()),
+        Geometry::LineString(linestring_2d()),
+        Geometry::MultiPolygon(multi_polygon_2d()),
+    ]
+    .iter()
+    .map(|geom| {
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, geom, Default::default()).unwrap();
+        buf
+    })
+    .collect::<Vec<_>>();
+
+    let parsed: Vec<Wkb> = buffers.iter().map(|buf| read_wkb(buf).unwrap()).collect();
+    for (geom, buf) in parsed.iter().zip(&buffers) {
+        assert_eq!(geom.size(), buf.len() as u64);
+        assert_eq!(geom.dimension(), WKBDimension::Xy);
+    }
+
+    match &parsed[2] {
+        Wkb::MultiPolygon(mp) => assert_eq!(mp.size(), buffers[2].len() as u64),
+        _ => panic!("expected a MultiPolygon"),
+    }
+}
+
+/// Write a multi-geometry header followed by parts that alternate between byte orders
+fn mixed_endian_multi(
+    code: u32,
+    num_parts: u32,
+    write_part: impl Fn(&mut Vec<u8>, Endianness),
+) -> Vec<u8> {
+    let mut buf = vec![1];
+    buf.extend_from_slice(&code.to_le_bytes());
+    buf.extend_from_slice(&num_parts.to_le_bytes());
+    for i in 0..num_parts {
+        let endianness = if i % 2 == 0 {
+            Endianness::BigEndian
+        } else {
+            Endianness::LittleEndian
+        };
+        write_part(&mut buf, endianness);
+    }
+    buf
+}
+
+#[test]
+fn read_mixed_endian_parts() {
+    let point = point_2d();
+    let buf = mixed_endian_multi(4, 2, |buf, endianness| {
+        write_point(
+            buf,
+            &point,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    });
+    let expected = geo_types::MultiPoint::new(vec![point; 2]);
+    assert_eq!(
+        Geometry::MultiPoint(expected),
+        read_wkb(&buf).unwrap().to_geometry()
+    );
+
+    let line_string = linestring_2d();
+    let buf = mixed_endian_multi(5, 2, |buf, endianness| {
+        write_line_string(
+            buf,
+            &line_string,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    });
+    let expected = geo_types::MultiLineString::new(vec![line_string.clone(); 2]);
+    assert_eq!(
+        Geometry::MultiLineString(expected),
+        read_wkb(&buf).unwrap().to_geometry()
+    );
+
+    let polygon = polygon_2d_with_interior();
+    let buf = mixed_endian_multi(6, 2, |buf, endianness| {
+        write_polygon(
+            buf,
+            &polygon,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    });
+    let expected = geo_types::MultiPolygon::new(vec![polygon.clone(); 2]);
+    assert_eq!(
+        Geometry::MultiPolygon(expected),
+        read_wkb(&buf).unwrap().to_geometry()
+    );
+
+    let buf = mixed_endian_multi(7, 2, |buf, endianness| {
+        write_polygon(
+            buf,
+            &polygon,
+            WriteOptions {
+                endianness,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    });
+    let expected = geo_types::GeometryCollection::new_from(vec![
+        Geometry::Polygon(polygon.clone()),
+        Geometry::Polygon(polygon.clone()),
+    ]);
+    assert_eq!(
+        Geometry::GeometryCollection(expected),
+        read_wkb(&buf).unwrap().to_geometry()
+    );
+}
+
+#[test]
+fn write_non_f64_coordinates() {
+    let to_wkb = |geom: &Geometry<f64>| {
+        let mut buf = Vec::new();
+        write_geometry(&mut buf, geom, Default::default()).unwrap();
+        buf
+    };
+
+    let point = geo_types::Point::<i32>::new(-3, 7);
+    let mut buf = Vec::new();
+    write_point(&mut buf, &point, Default::default()).unwrap();
+    let expected = geo_types::Point::new(-3., 7.);
+    assert_eq!(buf, to_wkb(&Geometry::Point(expected)));
+
+    let polygon = geo_types::Polygon::<f32>::new(
+        vec![(0., 0.), (4., 0.), (4., 2.5), (0., 0.)].into(),
+        vec![vec![(1., 0.5), (2., 0.5), (2., 1.), (1., 0.5)].into()],
+    );
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &Geometry::Polygon(polygon), Default::default()).unwrap();
+    let expected = geo_types::Polygon::<f64>::new(
+        vec![(0., 0.), (4., 0.), (4., 2.5), (0., 0.)].into(),
+        vec![vec![(1., 0.5), (2., 0.5), (2., 1.), (1., 0.5)].into()],
+    );
+    assert_eq!(buf, to_wkb(&Geometry::Polygon(expected)));
+
+    let rect = geo_types::Rect::<i32>::new((0, 0), (10, 20));
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, &Geometry::Rect(rect), Default::default()).unwrap();
+    let expected = geo_types::Rect::<f64>::new((0., 0.), (10., 20.));
+    assert_eq!(buf, to_wkb(&Geometry::Rect(expected)));
+}
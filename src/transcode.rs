@@ -0,0 +1,492 @@
+//! Rewrite WKB buffers without decoding their geometries.
+//!
+//! These functions walk the structure of a WKB or EWKB geometry, including the SQL/MM curve
+//! types and nested collections, and rewrite its bytes directly instead of reading the geometry
+//! and writing it again through [`geo_traits`].
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::bounds::value_indices;
+use crate::common::{WKBDimension, WKBGeometryCode, WKBType};
+use crate::error::WKBResult;
+use crate::reader::util::{check_bounds, check_depth, read_header, read_srid, read_u32_at};
+use crate::Endianness;
+
+/// Convert the WKB geometry at the start of `buf` to the byte order `target`, returning a new
+/// buffer.
+///
+/// The byte order, geometry code, SRID, counts and coordinates of the geometry and of every
+/// nested geometry are rewritten, so a buffer that mixes byte orders is converted entirely to
+/// `target`. Any bytes after the end of the geometry are not included in the result.
+///
+/// ```
+/// use wkb::transcode::convert_byte_order;
+/// use wkb::writer::{write_geometry, WriteOptions};
+/// use wkb::Endianness;
+///
+/// let point = geo_types::Point::new(1., 2.);
+/// let mut big_endian = Vec::new();
+/// let options = WriteOptions {
+///     endianness: Endianness::BigEndian,
+///     ..Default::default()
+/// };
+/// write_geometry(&mut big_endian, &point, options).unwrap();
+///
+/// let mut little_endian = Vec::new();
+/// write_geometry(&mut little_endian, &point, Default::default()).unwrap();
+///
+/// let converted = convert_byte_order(&big_endian, Endianness::LittleEndian).unwrap();
+/// assert_eq!(converted, little_endian);
+/// ```
+pub fn convert_byte_order(buf: &[u8], target: Endianness) -> WKBResult<Vec<u8>> {
+    let mut out = buf.to_vec();
+    let size = convert_byte_order_in_place(&mut out, target)?;
+    out.truncate(size);
+    Ok(out)
+}
+
+/// Convert the WKB geometry at the start of `buf` to the byte order `target` in place, returning
+/// the number of bytes in the geometry.
+///
+/// See [`convert_byte_order`]. The whole geometry is validated before any byte is changed, so
+/// `buf` is left untouched if this returns an error.
+pub fn convert_byte_order_in_place(buf: &mut [u8], target: Endianness) -> WKBResult<usize> {
+    ByteOrderConverter::check(target).geometry(buf, 0, 0)?;
+    let size = ByteOrderConverter::apply(target).geometry(buf, 0, 0)?;
+    Ok(size as usize)
+}
+
+/// Convert the WKB geometry at the start of `buf` to a new WKB geometry with z and m values
+/// dropped or added as set by `options`.
+///
+/// The geometry code of the geometry and of every nested geometry is rewritten for its new
+/// dimension. Each geometry keeps its byte order and flavor, and any SRID. Coordinates whose
+/// values are all NaN, such as those of empty Points, stay all NaN.
+///
+/// ```
+/// use wkb::transcode::{convert_dimension, DimensionChange, DimensionOptions};
+/// use wkb::writer::write_geometry;
+///
+/// let xy = geo_types::LineString::from(vec![(1., 2.), (3., 4.)]);
+/// let mut buf = Vec::new();
+/// write_geometry(&mut buf, &xy, Default::default()).unwrap();
+///
+/// let options = DimensionOptions {
+///     z: DimensionChange::Add(0.),
+///     ..Default::default()
+/// };
+/// let xyz = convert_dimension(&buf, options).unwrap();
+/// assert_eq!(xyz[1..5], 1002_u32.to_le_bytes());
+///
+/// let converted = convert_dimension(&xyz, DimensionOptions::FORCE_2D).unwrap();
+/// assert_eq!(converted, buf);
+/// ```
+pub fn convert_dimension(buf: &[u8], options: DimensionOptions) -> WKBResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(buf.len());
+    let rewriter = Rewriter {
+        dimension: options,
+        flavor: FlavorChange::Keep,
+    };
+    rewriter.geometry(buf, 0, 0, &mut out)?;
+    Ok(out)
+}
+
+/// Convert the WKB or EWKB geometry at the start of `buf` to ISO WKB, dropping any SRID.
+///
+/// The geometry code of the geometry and of every nested geometry is rewritten with the ISO
+/// dimension offsets. Each geometry keeps its byte order.
+///
+/// ```
+/// use wkb::transcode::{convert_to_ewkb, convert_to_iso, SridChange};
+/// use wkb::writer::write_geometry;
+///
+/// let point = geo_types::Point::new(1., 2.);
+/// let mut iso = Vec::new();
+/// write_geometry(&mut iso, &point, Default::default()).unwrap();
+///
+/// let ewkb = convert_to_ewkb(&iso, SridChange::Set(4326)).unwrap();
+/// assert_eq!(ewkb[1..9], [0x01, 0x00, 0x00, 0x20, 0xe6, 0x10, 0x00, 0x00]);
+/// assert_eq!(convert_to_iso(&ewkb).unwrap(), iso);
+/// ```
+pub fn convert_to_iso(buf: &[u8]) -> WKBResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(buf.len());
+    let rewriter = Rewriter {
+        dimension: Default::default(),
+        flavor: FlavorChange::Iso,
+    };
+    rewriter.geometry(buf, 0, 0, &mut out)?;
+    Ok(out)
+}
+
+/// Convert the WKB or EWKB geometry at the start of `buf` to EWKB, keeping, stripping or setting
+/// the SRID of the geometry as set by `srid`.
+///
+/// The geometry code of the geometry and of every nested geometry is rewritten with the EWKB
+/// dimension flags. Each geometry keeps its byte order. As with
+/// [`WKBFlavor::Extended`][crate::WKBFlavor::Extended], an SRID is only written for the
+/// top-level geometry, and any SRIDs of nested geometries are dropped.
+pub fn convert_to_ewkb(buf: &[u8], srid: SridChange) -> WKBResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(buf.len());
+    let rewriter = Rewriter {
+        dimension: Default::default(),
+        flavor: FlavorChange::Extended(srid),
+    };
+    rewriter.geometry(buf, 0, 0, &mut out)?;
+    Ok(out)
+}
+
+/// What to do with the SRID of a geometry converted with [`convert_to_ewkb`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SridChange {
+    /// Keep any SRID of the geometry
+    #[default]
+    Keep,
+    /// Drop any SRID of the geometry
+    Strip,
+    /// Set the SRID of the geometry, adding or replacing it
+    Set(i32),
+}
+
+/// The flavor to rewrite each geometry in
+#[derive(Debug, Clone, Copy)]
+enum FlavorChange {
+    /// Keep the flavor and SRID of each geometry
+    Keep,
+    Iso,
+    Extended(SridChange),
+}
+
+/// What to do with the z or m values of a geometry
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DimensionChange {
+    /// Keep the values if the geometry has them
+    #[default]
+    Keep,
+    /// Drop the values
+    Drop,
+    /// Keep the values if the geometry has them, and add them with this value otherwise
+    Add(f64),
+}
+
+impl DimensionChange {
+    fn apply(&self, has_value: bool) -> bool {
+        match self {
+            Self::Keep => has_value,
+            Self::Drop => false,
+            Self::Add(_) => true,
+        }
+    }
+}
+
+/// How to change the dimension of a geometry with [`convert_dimension`]
+///
+/// The default keeps both z and m values.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DimensionOptions {
+    /// What to do with z values
+    pub z: DimensionChange,
+    /// What to do with m values
+    pub m: DimensionChange,
+}
+
+impl DimensionOptions {
+    /// Options that drop any z and m values
+    pub const FORCE_2D: Self = Self {
+        z: DimensionChange::Drop,
+        m: DimensionChange::Drop,
+    };
+
+    /// The dimension of a geometry with dimension `dim` once converted
+    fn output_dimension(&self, dim: WKBDimension) -> WKBDimension {
+        WKBDimension::from_flags(self.z.apply(dim.has_z()), self.m.apply(dim.has_m()))
+    }
+
+    /// The value to add for dimension `i` of x, y, z and m, which the input does not have
+    fn fill(&self, i: usize) -> f64 {
+        let change = if i == 2 { self.z } else { self.m };
+        match change {
+            DimensionChange::Add(value) => value,
+            _ => unreachable!("only added dimensions are filled"),
+        }
+    }
+}
+
+/// Walks the structure of a WKB geometry, swapping the byte order of each value that is not
+/// already in the target byte order
+struct ByteOrderConverter {
+    target: Endianness,
+    /// Whether to rewrite the buffer, or only check that the geometry is valid
+    apply: bool,
+}
+
+impl ByteOrderConverter {
+    fn check(target: Endianness) -> Self {
+        Self {
+            target,
+            apply: false,
+        }
+    }
+
+    fn apply(target: Endianness) -> Self {
+        Self {
+            target,
+            apply: true,
+        }
+    }
+
+    /// Convert the geometry starting at `offset`, nested `depth` levels deep, returning its size
+    fn geometry(&self, buf: &mut [u8], offset: u64, depth: usize) -> WKBResult<u64> {
+        check_depth(depth, offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        let srid = read_srid(buf, byte_order, offset)?;
+        let dim = wkb_type.dimension();
+
+        self.swap_u32(buf, byte_order, offset + 1);
+        let mut body_offset = offset + 1 + 4;
+        if srid.is_some() {
+            self.swap_u32(buf, byte_order, body_offset);
+            body_offset += 4;
+        }
+
+        let body_size = match wkb_type {
+            WKBType::Point(_) => self.coords(buf, byte_order, body_offset, 1, dim)?,
+            WKBType::LineString(_) | WKBType::CircularString(_) => {
+                self.coord_sequence(buf, byte_order, body_offset, dim)?
+            }
+            WKBType::Polygon(_) | WKBType::Triangle(_) => {
+                let num_rings = self.count(buf, byte_order, body_offset)?;
+                let mut ring_offset = body_offset + 4;
+                for i in 0..num_rings {
+                    ring_offset += self
+                        .coord_sequence(buf, byte_order, ring_offset, dim)
+                        .map_err(|err| err.in_part(i))?;
+                }
+                ring_offset - body_offset
+            }
+            // Every other type is a sequence of nested geometries, each with its own header
+            _ => {
+                let num_parts = self.count(buf, byte_order, body_offset)?;
+                let mut part_offset = body_offset + 4;
+                for i in 0..num_parts {
+                    part_offset += self
+                        .geometry(buf, part_offset, depth + 1)
+                        .map_err(|err| err.in_part(i))?;
+                }
+                part_offset - body_offset
+            }
+        };
+
+        if self.apply {
+            buf[offset as usize] = self.target.into();
+        }
+        Ok(body_offset - offset + body_size)
+    }
+
+    /// Convert a count followed by that many coordinates, returning its size
+    fn coord_sequence(
+        &self,
+        buf: &mut [u8],
+        byte_order: Endianness,
+        offset: u64,
+        dim: WKBDimension,
+    ) -> WKBResult<u64> {
+        let num_coords = self.count(buf, byte_order, offset)?;
+        Ok(4 + self.coords(buf, byte_order, offset + 4, num_coords, dim)?)
+    }
+
+    /// Convert the count at `offset`, returning its value
+    fn count(&self, buf: &mut [u8], byte_order: Endianness, offset: u64) -> WKBResult<usize> {
+        let count = read_u32_at(buf, byte_order, offset)? as usize;
+        self.swap_u32(buf, byte_order, offset);
+        Ok(count)
+    }
+
+    /// Convert `num_coords` coordinates starting at `offset`, returning their size
+    fn coords(
+        &self,
+        buf: &mut [u8],
+        byte_order: Endianness,
+        offset: u64,
+        num_coords: usize,
+        dim: WKBDimension,
+    ) -> WKBResult<u64> {
+        let size = num_coords as u64 * dim.size() as u64 * 8;
+        check_bounds(buf, offset, size)?;
+        if self.apply && byte_order != self.target {
+            let bytes = &mut buf[offset as usize..(offset + size) as usize];
+            for value in bytes.chunks_exact_mut(8) {
+                value.reverse();
+            }
+        }
+        Ok(size)
+    }
+
+    /// Reverse the 4-byte value at `offset`, which is within the buffer, if `byte_order` is not
+    /// the target byte order
+    fn swap_u32(&self, buf: &mut [u8], byte_order: Endianness, offset: u64) {
+        if self.apply && byte_order != self.target {
+            buf[offset as usize..offset as usize + 4].reverse();
+        }
+    }
+}
+
+/// Walks the structure of a WKB geometry, writing a rewritten copy of it
+struct Rewriter {
+    dimension: DimensionOptions,
+    flavor: FlavorChange,
+}
+
+impl Rewriter {
+    /// Rewrite the geometry starting at `offset`, nested `depth` levels deep, to `out`,
+    /// returning its size in `buf`
+    fn geometry(&self, buf: &[u8], offset: u64, depth: usize, out: &mut Vec<u8>) -> WKBResult<u64> {
+        check_depth(depth, offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        let srid = read_srid(buf, byte_order, offset)?;
+        let geometry_code = WKBGeometryCode::new(read_u32_at(buf, byte_order, offset + 1)?);
+        let dim = wkb_type.dimension();
+        let out_dim = self.dimension.output_dimension(dim);
+
+        let (is_extended, out_srid) = match self.flavor {
+            FlavorChange::Keep => (geometry_code.is_extended(), srid),
+            FlavorChange::Iso => (false, None),
+            FlavorChange::Extended(_) if depth > 0 => (true, None),
+            FlavorChange::Extended(SridChange::Keep) => (true, srid),
+            FlavorChange::Extended(SridChange::Strip) => (true, None),
+            FlavorChange::Extended(SridChange::Set(srid)) => (true, Some(srid)),
+        };
+        let out_type = wkb_type.with_dimension(out_dim);
+        let out_code = if is_extended {
+            out_type.as_ewkb_geometry_code(out_srid.is_some())
+        } else {
+            out_type.as_geometry_code()
+        };
+        out.push(byte_order.into());
+        push_u32(out, byte_order, out_code.into());
+        if let Some(srid) = out_srid {
+            push_u32(out, byte_order, srid as u32);
+        }
+        let mut body_offset = offset + 1 + 4;
+        if srid.is_some() {
+            body_offset += 4;
+        }
+
+        let coords = CoordRewriter {
+            byte_order,
+            dim,
+            out_dim,
+            options: &self.dimension,
+        };
+        let body_size = match wkb_type {
+            WKBType::Point(_) => coords.coords(buf, body_offset, 1, out)?,
+            WKBType::LineString(_) | WKBType::CircularString(_) => {
+                coords.coord_sequence(buf, body_offset, out)?
+            }
+            WKBType::Polygon(_) | WKBType::Triangle(_) => {
+                let num_rings = coords.count(buf, body_offset, out)?;
+                let mut ring_offset = body_offset + 4;
+                for i in 0..num_rings {
+                    ring_offset += coords
+                        .coord_sequence(buf, ring_offset, out)
+                        .map_err(|err| err.in_part(i))?;
+                }
+                ring_offset - body_offset
+            }
+            // Every other type is a sequence of nested geometries, each with its own header
+            _ => {
+                let num_parts = coords.count(buf, body_offset, out)?;
+                let mut part_offset = body_offset + 4;
+                for i in 0..num_parts {
+                    part_offset += self
+                        .geometry(buf, part_offset, depth + 1, out)
+                        .map_err(|err| err.in_part(i))?;
+                }
+                part_offset - body_offset
+            }
+        };
+        Ok(body_offset - offset + body_size)
+    }
+}
+
+/// Rewrites the counts and coordinates of a single geometry
+struct CoordRewriter<'a> {
+    byte_order: Endianness,
+    dim: WKBDimension,
+    out_dim: WKBDimension,
+    options: &'a DimensionOptions,
+}
+
+impl CoordRewriter<'_> {
+    /// Rewrite a count followed by that many coordinates, returning its size in `buf`
+    fn coord_sequence(&self, buf: &[u8], offset: u64, out: &mut Vec<u8>) -> WKBResult<u64> {
+        let num_coords = self.count(buf, offset, out)?;
+        Ok(4 + self.coords(buf, offset + 4, num_coords, out)?)
+    }
+
+    /// Copy the count at `offset`, returning its value
+    fn count(&self, buf: &[u8], offset: u64, out: &mut Vec<u8>) -> WKBResult<usize> {
+        let count = read_u32_at(buf, self.byte_order, offset)?;
+        push_u32(out, self.byte_order, count);
+        Ok(count as usize)
+    }
+
+    /// Rewrite `num_coords` coordinates starting at `offset`, returning their size in `buf`
+    fn coords(
+        &self,
+        buf: &[u8],
+        offset: u64,
+        num_coords: usize,
+        out: &mut Vec<u8>,
+    ) -> WKBResult<u64> {
+        let coord_size = self.dim.size() * 8;
+        let size = num_coords as u64 * coord_size as u64;
+        check_bounds(buf, offset, size)?;
+        let bytes = &buf[offset as usize..(offset + size) as usize];
+        if self.dim == self.out_dim {
+            out.extend_from_slice(bytes);
+            return Ok(size);
+        }
+
+        // The index into the input coordinate of each value of the output coordinate, or `None`
+        // for a value to add
+        let in_indices = value_indices(self.dim.into());
+        let sources = value_indices(self.out_dim.into())
+            .iter()
+            .map(|i| (*i, in_indices.iter().position(|j| j == i)));
+
+        for coord in bytes.chunks_exact(coord_size) {
+            let is_empty = coord
+                .chunks_exact(8)
+                .all(|value| read_f64(value, self.byte_order).is_nan());
+            for (i, source) in sources.clone() {
+                match source {
+                    Some(n) => out.extend_from_slice(&coord[n * 8..n * 8 + 8]),
+                    None if is_empty => push_f64(out, self.byte_order, f64::NAN),
+                    None => push_f64(out, self.byte_order, self.options.fill(i)),
+                }
+            }
+        }
+        Ok(size)
+    }
+}
+
+fn read_f64(bytes: &[u8], byte_order: Endianness) -> f64 {
+    match byte_order {
+        Endianness::BigEndian => BigEndian::read_f64(bytes),
+        Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+    }
+}
+
+fn push_u32(out: &mut Vec<u8>, byte_order: Endianness, value: u32) {
+    match byte_order {
+        Endianness::BigEndian => out.extend_from_slice(&value.to_be_bytes()),
+        Endianness::LittleEndian => out.extend_from_slice(&value.to_le_bytes()),
+    }
+}
+
+fn push_f64(out: &mut Vec<u8>, byte_order: Endianness, value: f64) {
+    match byte_order {
+        Endianness::BigEndian => out.extend_from_slice(&value.to_be_bytes()),
+        Endianness::LittleEndian => out.extend_from_slice(&value.to_le_bytes()),
+    }
+}
@@ -0,0 +1,78 @@
+//! Read and write geometries in the [Tiny Well-Known Binary][spec] (TWKB) encoding.
+//!
+//! TWKB stores coordinates as integers at a fixed decimal precision, each as a variable-length
+//! delta from the previous coordinate, which makes it much smaller than WKB. This is the
+//! encoding produced by PostGIS `ST_AsTWKB`.
+//!
+//! [spec]: https://github.com/TWKB/Specification/blob/master/twkb.md
+
+use crate::error::{WKBError, WKBResult};
+use crate::reader::util::read_u8_at;
+
+mod reader;
+mod writer;
+
+pub use reader::{read_twkb, Twkb};
+pub use writer::{write_twkb, write_twkb_with_ids, TwkbOptions};
+
+/// TWKB geometry type codes, stored in the low four bits of the first header byte
+const TWKB_POINT: u8 = 1;
+const TWKB_LINESTRING: u8 = 2;
+const TWKB_POLYGON: u8 = 3;
+const TWKB_MULTIPOINT: u8 = 4;
+const TWKB_MULTILINESTRING: u8 = 5;
+const TWKB_MULTIPOLYGON: u8 = 6;
+const TWKB_GEOMETRYCOLLECTION: u8 = 7;
+
+/// Bit flags of the metadata header byte
+const FLAG_BBOX: u8 = 0x01;
+const FLAG_SIZE: u8 = 0x02;
+const FLAG_ID_LIST: u8 = 0x04;
+const FLAG_EXTENDED_DIMENSIONS: u8 = 0x08;
+const FLAG_EMPTY: u8 = 0x10;
+
+/// Bit flags of the extended dimensions byte
+const FLAG_Z: u8 = 0x01;
+const FLAG_M: u8 = 0x02;
+
+/// Map a signed integer to an unsigned one so that values near zero have short varints
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// The inverse of [`zigzag`]
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Append `value` as an unsigned LEB128 varint
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Read an unsigned LEB128 varint at `offset`, advancing `offset` past it
+fn read_varint(buf: &[u8], offset: &mut u64) -> WKBResult<u64> {
+    let start = *offset;
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = read_u8_at(buf, *offset)?;
+        // The tenth byte may only hold the highest bit of a u64
+        if shift == 63 && byte > 1 {
+            return Err(WKBError::InvalidVarint {
+                offset: start,
+                path: vec![],
+            });
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        *offset += 1;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
@@ -0,0 +1,301 @@
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::common::{WKBDimension, WKBType};
+use crate::error::{WKBError, WKBResult};
+use crate::reader::util::{check_bounds, check_depth, read_u8_at};
+use crate::reader::{read_wkb, Wkb};
+use crate::twkb::*;
+use crate::Endianness;
+
+/// A geometry decoded from TWKB.
+///
+/// The geometry is decoded into little-endian ISO WKB, and is accessed through
+/// [`Twkb::geometry`], which implements the `geo_traits` geometry traits.
+#[derive(Debug, Clone)]
+pub struct Twkb {
+    wkb: Vec<u8>,
+    ids: Option<Vec<i64>>,
+    size: usize,
+}
+
+impl Twkb {
+    /// The decoded geometry.
+    pub fn geometry(&self) -> Wkb<'_> {
+        read_wkb(&self.wkb).expect("decoded TWKB is valid WKB")
+    }
+
+    /// The ids of each part of a multi-geometry or GeometryCollection, if it has an id list.
+    ///
+    /// Only the ids of the outermost geometry are kept.
+    pub fn ids(&self) -> Option<&[i64]> {
+        self.ids.as_deref()
+    }
+
+    /// The number of bytes of TWKB that were decoded.
+    pub fn twkb_size(&self) -> usize {
+        self.size
+    }
+
+    /// The decoded geometry encoded as little-endian ISO WKB.
+    pub fn as_wkb(&self) -> &[u8] {
+        &self.wkb
+    }
+
+    /// Consume this geometry, returning it encoded as little-endian ISO WKB.
+    pub fn into_wkb(self) -> Vec<u8> {
+        self.wkb
+    }
+}
+
+/// Decode a TWKB geometry from the start of a buffer.
+///
+/// Any bytes after the end of the geometry are ignored; [`Twkb::twkb_size`] gives the number of
+/// bytes that were read.
+///
+/// ```
+/// use geo_traits::{GeometryTrait, GeometryType, LineStringTrait};
+/// use wkb::twkb::read_twkb;
+///
+/// // SELECT ST_AsTWKB('LINESTRING(1 1, 5 5)')
+/// let twkb = read_twkb(&[0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08]).unwrap();
+/// let geometry = twkb.geometry();
+/// let GeometryType::LineString(line_string) = geometry.as_type() else {
+///     unreachable!()
+/// };
+/// assert_eq!(line_string.num_coords(), 2);
+/// ```
+pub fn read_twkb(buf: &[u8]) -> WKBResult<Twkb> {
+    let mut decoder = Decoder {
+        buf,
+        offset: 0,
+        out: Vec::new(),
+    };
+    let (_, ids) = decoder.read_geometry(0)?;
+    Ok(Twkb {
+        wkb: decoder.out,
+        ids,
+        size: decoder.offset as usize,
+    })
+}
+
+/// The header of a TWKB geometry
+struct Header {
+    geometry_type: u8,
+    dim: WKBDimension,
+    /// The divisor of each coordinate value, from its precision
+    scales: [f64; 4],
+    has_ids: bool,
+    is_empty: bool,
+}
+
+/// The state of the coordinates of a single TWKB geometry, each of which is a delta from the last
+struct Coords {
+    dim: WKBDimension,
+    scales: [f64; 4],
+    last: [i64; 4],
+}
+
+/// Decodes TWKB into WKB written to `out`
+struct Decoder<'a> {
+    buf: &'a [u8],
+    offset: u64,
+    out: Vec<u8>,
+}
+
+impl Decoder<'_> {
+    /// Decode a geometry with its header, nested `depth` levels deep, returning its dimension and
+    /// any id list
+    fn read_geometry(&mut self, depth: usize) -> WKBResult<(WKBDimension, Option<Vec<i64>>)> {
+        check_depth(depth, self.offset)?;
+        let header = self.read_header()?;
+        let dim = header.dim;
+        let mut coords = Coords {
+            dim,
+            scales: header.scales,
+            last: [0; 4],
+        };
+
+        let wkb_type = match header.geometry_type {
+            TWKB_POINT => WKBType::Point(dim),
+            TWKB_LINESTRING => WKBType::LineString(dim),
+            TWKB_POLYGON => WKBType::Polygon(dim),
+            TWKB_MULTIPOINT => WKBType::MultiPoint(dim),
+            TWKB_MULTILINESTRING => WKBType::MultiLineString(dim),
+            TWKB_MULTIPOLYGON => WKBType::MultiPolygon(dim),
+            _ => WKBType::GeometryCollection(dim),
+        };
+        self.write_wkb_header(wkb_type)?;
+
+        if header.is_empty {
+            if header.geometry_type == TWKB_POINT {
+                for _ in 0..dim.size() {
+                    self.out.write_f64::<LittleEndian>(f64::NAN)?;
+                }
+            } else {
+                self.out.write_u32::<LittleEndian>(0)?;
+            }
+            return Ok((dim, None));
+        }
+
+        let mut ids = None;
+        match header.geometry_type {
+            TWKB_POINT => self.read_coords(&mut coords, 1)?,
+            TWKB_LINESTRING => self.read_line_string(&mut coords)?,
+            TWKB_POLYGON => self.read_polygon(&mut coords)?,
+            TWKB_MULTIPOINT => {
+                let num_points = self.read_count(dim.size() as u64, header.has_ids)?;
+                ids = self.read_ids(num_points, header.has_ids)?;
+                for i in 0..num_points {
+                    self.write_wkb_header(WKBType::Point(dim))?;
+                    self.read_coords(&mut coords, 1)
+                        .map_err(|err| err.in_part(i as usize))?;
+                }
+            }
+            TWKB_MULTILINESTRING => {
+                let num_line_strings = self.read_count(1, header.has_ids)?;
+                ids = self.read_ids(num_line_strings, header.has_ids)?;
+                for i in 0..num_line_strings {
+                    self.write_wkb_header(WKBType::LineString(dim))?;
+                    self.read_line_string(&mut coords)
+                        .map_err(|err| err.in_part(i as usize))?;
+                }
+            }
+            TWKB_MULTIPOLYGON => {
+                let num_polygons = self.read_count(1, header.has_ids)?;
+                ids = self.read_ids(num_polygons, header.has_ids)?;
+                for i in 0..num_polygons {
+                    self.write_wkb_header(WKBType::Polygon(dim))?;
+                    self.read_polygon(&mut coords)
+                        .map_err(|err| err.in_part(i as usize))?;
+                }
+            }
+            _ => {
+                // Each geometry in a collection has its own header
+                let num_geometries = self.read_count(2, header.has_ids)?;
+                ids = self.read_ids(num_geometries, header.has_ids)?;
+                for i in 0..num_geometries {
+                    let offset = self.offset;
+                    let (child, _) = self
+                        .read_geometry(depth + 1)
+                        .map_err(|err| err.in_part(i as usize))?;
+                    if child != dim {
+                        return Err(WKBError::DimensionMismatch {
+                            parent: dim,
+                            child,
+                            offset,
+                            path: vec![i as usize],
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok((dim, ids))
+    }
+
+    fn read_header(&mut self) -> WKBResult<Header> {
+        let start = self.offset;
+        let type_and_precision = read_u8_at(self.buf, self.offset)?;
+        let metadata = read_u8_at(self.buf, self.offset + 1)?;
+        self.offset += 2;
+
+        let geometry_type = type_and_precision & 0x0f;
+        if !(TWKB_POINT..=TWKB_GEOMETRYCOLLECTION).contains(&geometry_type) {
+            return Err(WKBError::UnknownGeometryCode {
+                code: geometry_type.into(),
+                offset: start,
+                path: vec![],
+            });
+        }
+        let xy_scale = 10_f64.powi(unzigzag((type_and_precision >> 4).into()) as i32);
+
+        let mut dim = WKBDimension::Xy;
+        let mut scales = [xy_scale, xy_scale, 1., 1.];
+        if metadata & FLAG_EXTENDED_DIMENSIONS != 0 {
+            let extended = read_u8_at(self.buf, self.offset)?;
+            self.offset += 1;
+            let z_scale = 10_f64.powi(((extended >> 2) & 0x07).into());
+            let m_scale = 10_f64.powi((extended >> 5).into());
+            (dim, scales[2], scales[3]) = match (extended & FLAG_Z != 0, extended & FLAG_M != 0) {
+                (false, false) => (WKBDimension::Xy, 1., 1.),
+                (true, false) => (WKBDimension::Xyz, z_scale, 1.),
+                (false, true) => (WKBDimension::Xym, m_scale, 1.),
+                (true, true) => (WKBDimension::Xyzm, z_scale, m_scale),
+            };
+        }
+
+        let is_empty = metadata & FLAG_EMPTY != 0;
+        if !is_empty {
+            if metadata & FLAG_SIZE != 0 {
+                read_varint(self.buf, &mut self.offset)?;
+            }
+            if metadata & FLAG_BBOX != 0 {
+                // The minimum and extent of each dimension
+                for _ in 0..2 * dim.size() {
+                    read_varint(self.buf, &mut self.offset)?;
+                }
+            }
+        }
+
+        Ok(Header {
+            geometry_type,
+            dim,
+            scales,
+            has_ids: metadata & FLAG_ID_LIST != 0,
+            is_empty,
+        })
+    }
+
+    /// Read a count of parts and write it to the WKB, checking that the buffer has room for each
+    /// part to take at least `min_part_size` bytes
+    fn read_count(&mut self, min_part_size: u64, has_ids: bool) -> WKBResult<u64> {
+        let count = read_varint(self.buf, &mut self.offset)?;
+        let min_part_size = min_part_size + u64::from(has_ids);
+        check_bounds(self.buf, self.offset, count.saturating_mul(min_part_size))?;
+        self.out
+            .write_u32::<LittleEndian>(count.try_into().unwrap())?;
+        Ok(count)
+    }
+
+    fn read_ids(&mut self, count: u64, has_ids: bool) -> WKBResult<Option<Vec<i64>>> {
+        if !has_ids {
+            return Ok(None);
+        }
+        let ids = (0..count)
+            .map(|_| Ok(unzigzag(read_varint(self.buf, &mut self.offset)?)))
+            .collect::<WKBResult<_>>()?;
+        Ok(Some(ids))
+    }
+
+    fn read_coords(&mut self, coords: &mut Coords, num_coords: u64) -> WKBResult<()> {
+        for _ in 0..num_coords {
+            for n in 0..coords.dim.size() {
+                let delta = unzigzag(read_varint(self.buf, &mut self.offset)?);
+                coords.last[n] = coords.last[n].wrapping_add(delta);
+                self.out
+                    .write_f64::<LittleEndian>(coords.last[n] as f64 / coords.scales[n])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_line_string(&mut self, coords: &mut Coords) -> WKBResult<()> {
+        let num_coords = self.read_count(coords.dim.size() as u64, false)?;
+        self.read_coords(coords, num_coords)
+    }
+
+    fn read_polygon(&mut self, coords: &mut Coords) -> WKBResult<()> {
+        let num_rings = self.read_count(1, false)?;
+        for i in 0..num_rings {
+            self.read_line_string(coords)
+                .map_err(|err| err.in_part(i as usize))?;
+        }
+        Ok(())
+    }
+
+    fn write_wkb_header(&mut self, wkb_type: WKBType) -> WKBResult<()> {
+        self.out.write_u8(Endianness::LittleEndian.into())?;
+        self.out.write_u32::<LittleEndian>(wkb_type.into())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,342 @@
+use std::io::Write;
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::common::WKBDimension;
+use crate::error::{WKBError, WKBResult};
+use crate::twkb::*;
+use crate::writer::{LineWrapper, RectWrapper, TriangleWrapper};
+
+/// Options for encoding geometries as TWKB
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TwkbOptions {
+    /// The number of decimal digits of x and y values to keep, from -8 to 7.
+    ///
+    /// A negative precision rounds to tens, hundreds and so on.
+    pub xy_precision: i8,
+    /// The number of decimal digits of z values to keep, from 0 to 7
+    pub z_precision: u8,
+    /// The number of decimal digits of m values to keep, from 0 to 7
+    pub m_precision: u8,
+    /// Whether to write the bounding box of each geometry
+    pub bbox: bool,
+    /// Whether to write the size in bytes of each geometry, so that readers can skip it
+    pub size: bool,
+}
+
+impl TwkbOptions {
+    fn validate(&self) -> WKBResult<()> {
+        if !(-8..=7).contains(&self.xy_precision) || self.z_precision > 7 || self.m_precision > 7 {
+            return Err(WKBError::General(format!(
+                "TWKB precision out of range: xy {}, z {}, m {}",
+                self.xy_precision, self.z_precision, self.m_precision
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Write a Geometry to a Writer encoded as TWKB
+///
+/// Rects and Triangles are written as Polygons, and Lines as LineStrings.
+///
+/// ```
+/// use wkb::twkb::{write_twkb, TwkbOptions};
+///
+/// let line_string = geo_types::LineString::from(vec![(1., 1.), (5., 5.)]);
+/// let mut buf = Vec::new();
+/// write_twkb(&mut buf, &line_string, TwkbOptions::default()).unwrap();
+/// assert_eq!(buf, [0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08]);
+/// ```
+pub fn write_twkb(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    options: TwkbOptions,
+) -> WKBResult<()> {
+    options.validate()?;
+    let mut out = Vec::new();
+    write_geometry(&mut out, geom, None, options)?;
+    writer.write_all(&out)?;
+    Ok(())
+}
+
+/// Write a multi-geometry or GeometryCollection to a Writer encoded as TWKB, with an id for each
+/// of its parts
+///
+/// `ids` must have one id for each part of `geom`.
+pub fn write_twkb_with_ids(
+    writer: &mut impl Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    ids: &[i64],
+    options: TwkbOptions,
+) -> WKBResult<()> {
+    options.validate()?;
+    let mut out = Vec::new();
+    write_geometry(&mut out, geom, Some(ids), options)?;
+    writer.write_all(&out)?;
+    Ok(())
+}
+
+/// The range of the integer coordinate values in each dimension of a geometry
+#[derive(Clone, Copy)]
+struct Bounds {
+    min: [i64; 4],
+    max: [i64; 4],
+}
+
+impl Bounds {
+    const EMPTY: Self = Self {
+        min: [i64::MAX; 4],
+        max: [i64::MIN; 4],
+    };
+
+    fn add(&mut self, n: usize, value: i64) {
+        self.min[n] = self.min[n].min(value);
+        self.max[n] = self.max[n].max(value);
+    }
+
+    fn extend(&mut self, other: &Self) {
+        for n in 0..4 {
+            self.min[n] = self.min[n].min(other.min[n]);
+            self.max[n] = self.max[n].max(other.max[n]);
+        }
+    }
+}
+
+/// Encodes the body of a single TWKB geometry, each coordinate a delta from the last
+struct Encoder {
+    dim: WKBDimension,
+    factors: [f64; 4],
+    last: [i64; 4],
+    bounds: Bounds,
+    body: Vec<u8>,
+}
+
+impl Encoder {
+    fn new(dim: WKBDimension, options: TwkbOptions) -> Self {
+        let xy = 10_f64.powi(options.xy_precision.into());
+        let z = 10_f64.powi(options.z_precision.into());
+        let m = 10_f64.powi(options.m_precision.into());
+        let factors = match dim {
+            WKBDimension::Xy | WKBDimension::Xyz => [xy, xy, z, 1.],
+            WKBDimension::Xym => [xy, xy, m, 1.],
+            WKBDimension::Xyzm => [xy, xy, z, m],
+        };
+        Self {
+            dim,
+            factors,
+            last: [0; 4],
+            bounds: Bounds::EMPTY,
+            body: Vec::new(),
+        }
+    }
+
+    fn write_count(&mut self, count: usize) {
+        write_varint(&mut self.body, count as u64);
+    }
+
+    /// Write the number of parts of a multi-geometry and its id list, if any
+    fn write_parts_header(&mut self, count: usize, ids: Option<&[i64]>) -> WKBResult<()> {
+        self.write_count(count);
+        if let Some(ids) = ids {
+            if ids.len() != count {
+                return Err(WKBError::General(format!(
+                    "Expected {} TWKB ids but got {}",
+                    count,
+                    ids.len()
+                )));
+            }
+            for id in ids {
+                write_varint(&mut self.body, zigzag(*id));
+            }
+        }
+        Ok(())
+    }
+
+    fn write_coord(&mut self, coord: &impl CoordTrait<T: Into<f64>>) -> WKBResult<()> {
+        for n in 0..self.dim.size() {
+            let value: f64 = coord.nth_or_panic(n).into();
+            let scaled = (value * self.factors[n]).round();
+            // The range of i64, whose bounds are exactly representable as f64
+            let limit = -(i64::MIN as f64);
+            if !(-limit..limit).contains(&scaled) {
+                return Err(WKBError::General(format!(
+                    "Coordinate value {} cannot be encoded as TWKB",
+                    value
+                )));
+            }
+            let scaled = scaled as i64;
+            write_varint(&mut self.body, zigzag(scaled.wrapping_sub(self.last[n])));
+            self.last[n] = scaled;
+            self.bounds.add(n, scaled);
+        }
+        Ok(())
+    }
+
+    fn write_line_string(&mut self, geom: &impl LineStringTrait<T: Into<f64>>) -> WKBResult<()> {
+        self.write_count(geom.num_coords());
+        for coord in geom.coords() {
+            self.write_coord(&coord)?;
+        }
+        Ok(())
+    }
+
+    fn write_polygon(&mut self, geom: &impl PolygonTrait<T: Into<f64>>) -> WKBResult<()> {
+        match geom.exterior() {
+            Some(exterior) => {
+                self.write_count(1 + geom.num_interiors());
+                self.write_line_string(&exterior)?;
+                for interior in geom.interiors() {
+                    self.write_line_string(&interior)?;
+                }
+            }
+            None => self.write_count(0),
+        }
+        Ok(())
+    }
+}
+
+/// Whether `geom` is written with the TWKB empty flag and no body
+fn is_empty(geom: &impl GeometryTrait<T: Into<f64>>) -> bool {
+    use GeometryType::*;
+    match geom.as_type() {
+        Point(p) => p.coord().is_none(),
+        LineString(ls) => ls.num_coords() == 0,
+        Polygon(p) => p.exterior().is_none(),
+        MultiPoint(mp) => mp.num_points() == 0,
+        MultiLineString(ml) => ml.num_line_strings() == 0,
+        MultiPolygon(mp) => mp.num_polygons() == 0,
+        GeometryCollection(gc) => gc.num_geometries() == 0,
+        Rect(_) | Triangle(_) | Line(_) => false,
+    }
+}
+
+/// Append `geom` with its header to `out`, returning its bounds
+fn write_geometry(
+    out: &mut Vec<u8>,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    ids: Option<&[i64]>,
+    options: TwkbOptions,
+) -> WKBResult<Bounds> {
+    use GeometryType::*;
+
+    let dim: WKBDimension = geom.dim().try_into()?;
+    let is_empty = is_empty(geom);
+    let mut encoder = Encoder::new(dim, options);
+
+    let geometry_type = match geom.as_type() {
+        Point(_) => TWKB_POINT,
+        LineString(_) | Line(_) => TWKB_LINESTRING,
+        Polygon(_) | Rect(_) | Triangle(_) => TWKB_POLYGON,
+        MultiPoint(_) => TWKB_MULTIPOINT,
+        MultiLineString(_) => TWKB_MULTILINESTRING,
+        MultiPolygon(_) => TWKB_MULTIPOLYGON,
+        GeometryCollection(_) => TWKB_GEOMETRYCOLLECTION,
+    };
+    if ids.is_some() && geometry_type < TWKB_MULTIPOINT {
+        return Err(WKBError::IncorrectType(
+            "Only multi-geometries and GeometryCollections can have TWKB ids".into(),
+        ));
+    }
+
+    if !is_empty {
+        match geom.as_type() {
+            Point(p) => encoder.write_coord(&p.coord().unwrap())?,
+            LineString(ls) => encoder.write_line_string(ls)?,
+            Polygon(p) => encoder.write_polygon(p)?,
+            MultiPoint(mp) => {
+                encoder.write_parts_header(mp.num_points(), ids)?;
+                for point in mp.points() {
+                    let coord = point.coord().ok_or_else(|| {
+                        WKBError::General(
+                            "TWKB cannot encode an empty point in a MultiPoint".into(),
+                        )
+                    })?;
+                    encoder.write_coord(&coord)?;
+                }
+            }
+            MultiLineString(ml) => {
+                encoder.write_parts_header(ml.num_line_strings(), ids)?;
+                for line_string in ml.line_strings() {
+                    encoder.write_line_string(&line_string)?;
+                }
+            }
+            MultiPolygon(mp) => {
+                encoder.write_parts_header(mp.num_polygons(), ids)?;
+                for polygon in mp.polygons() {
+                    encoder.write_polygon(&polygon)?;
+                }
+            }
+            GeometryCollection(gc) => {
+                encoder.write_parts_header(gc.num_geometries(), ids)?;
+                for geometry in gc.geometries() {
+                    let bounds = write_geometry(&mut encoder.body, &geometry, None, options)?;
+                    encoder.bounds.extend(&bounds);
+                }
+            }
+            Rect(r) => encoder.write_polygon(&RectWrapper(r))?,
+            Triangle(tri) => encoder.write_polygon(&TriangleWrapper(tri))?,
+            Line(line) => encoder.write_line_string(&LineWrapper(line))?,
+        }
+    }
+
+    // Header
+    out.push(geometry_type | ((zigzag(options.xy_precision.into()) as u8) << 4));
+    let has_extended_dimensions = dim != WKBDimension::Xy;
+    let mut metadata = 0;
+    if is_empty {
+        metadata |= FLAG_EMPTY;
+    } else {
+        if options.bbox {
+            metadata |= FLAG_BBOX;
+        }
+        if options.size {
+            metadata |= FLAG_SIZE;
+        }
+        if ids.is_some() {
+            metadata |= FLAG_ID_LIST;
+        }
+    }
+    if has_extended_dimensions {
+        metadata |= FLAG_EXTENDED_DIMENSIONS;
+    }
+    out.push(metadata);
+    if has_extended_dimensions {
+        let mut extended = (options.z_precision << 2) | (options.m_precision << 5);
+        if dim.has_z() {
+            extended |= FLAG_Z;
+        }
+        if dim.has_m() {
+            extended |= FLAG_M;
+        }
+        out.push(extended);
+    }
+    if is_empty {
+        return Ok(encoder.bounds);
+    }
+
+    let mut bbox = Vec::new();
+    if options.bbox {
+        let bounds = encoder.bounds;
+        for n in 0..dim.size() {
+            // A collection of empty geometries has no extent
+            let (min, max) = if bounds.min[n] <= bounds.max[n] {
+                (bounds.min[n], bounds.max[n])
+            } else {
+                (0, 0)
+            };
+            write_varint(&mut bbox, zigzag(min));
+            write_varint(&mut bbox, zigzag(max.wrapping_sub(min)));
+        }
+    }
+    if options.size {
+        write_varint(out, (bbox.len() + encoder.body.len()) as u64);
+    }
+    out.extend_from_slice(&bbox);
+    out.extend_from_slice(&encoder.body);
+
+    Ok(encoder.bounds)
+}
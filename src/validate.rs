@@ -0,0 +1,372 @@
+//! Check WKB geometries against the structural rules of OGC Simple Features.
+//!
+//! The reader accepts some WKB that is well-formed but breaks these rules, such as a LineString
+//! with a single point or an unclosed Polygon ring. [`validate_wkb`] walks a geometry and returns
+//! each such issue with its location, so that invalid geometries can be reported or set aside.
+
+use std::fmt;
+use std::mem::discriminant;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::common::{WKBDimension, WKBType};
+use crate::error::WKBResult;
+use crate::reader::util::{check_bounds, check_depth, read_header, read_srid, read_u32_at};
+use crate::Endianness;
+
+/// Which issues [`validate_wkb`] reports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strictness {
+    /// Only report issues with how geometries are nested: parts with the wrong type or
+    /// dimension, and nested geometries with an SRID
+    Lenient,
+    /// Also report geometries that break the OGC rules for their type: LineStrings and rings
+    /// with too few points, Triangle rings with too many points, unclosed rings and NaN
+    /// coordinates
+    #[default]
+    Strict,
+}
+
+/// The kind of a [`ValidationIssue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// A non-empty LineString with fewer than two points, or a ring with fewer than four points
+    TooFewPoints {
+        /// The number of points
+        found: usize,
+        /// The minimum number of points
+        min: usize,
+    },
+    /// A Triangle ring with more than four points
+    TooManyPoints {
+        /// The number of points
+        found: usize,
+        /// The maximum number of points
+        max: usize,
+    },
+    /// A ring whose first and last points differ
+    UnclosedRing,
+    /// A coordinate with a NaN value, other than the all-NaN coordinate of an empty Point
+    NanCoordinate,
+    /// A nested geometry whose dimension differs from its parent's
+    DimensionMismatch {
+        /// The dimension of the parent geometry
+        parent: WKBDimension,
+        /// The dimension of the nested geometry
+        child: WKBDimension,
+    },
+    /// A part of a multi-geometry, PolyhedralSurface or TIN with the wrong type
+    UnexpectedPartType {
+        /// The type the part should have
+        expected: WKBType,
+        /// The type of the part
+        found: WKBType,
+    },
+    /// A nested geometry with an SRID of its own
+    NestedSrid {
+        /// The SRID of the nested geometry
+        srid: i32,
+    },
+}
+
+impl IssueKind {
+    /// The strictness at which this issue is reported
+    fn strictness(&self) -> Strictness {
+        match self {
+            Self::DimensionMismatch { .. }
+            | Self::UnexpectedPartType { .. }
+            | Self::NestedSrid { .. } => Strictness::Lenient,
+            Self::TooFewPoints { .. }
+            | Self::TooManyPoints { .. }
+            | Self::UnclosedRing
+            | Self::NanCoordinate => Strictness::Strict,
+        }
+    }
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewPoints { found, min } => {
+                write!(f, "{} points where at least {} are needed", found, min)
+            }
+            Self::TooManyPoints { found, max } => {
+                write!(f, "{} points where at most {} are allowed", found, max)
+            }
+            Self::UnclosedRing => write!(f, "ring is not closed"),
+            Self::NanCoordinate => write!(f, "coordinate has a NaN value"),
+            Self::DimensionMismatch { parent, child } => {
+                write!(
+                    f,
+                    "dimension {:?} in a parent of dimension {:?}",
+                    child, parent
+                )
+            }
+            Self::UnexpectedPartType { expected, found } => {
+                write!(
+                    f,
+                    "expected part of type {:?} but found {:?}",
+                    expected, found
+                )
+            }
+            Self::NestedSrid { srid } => write!(f, "nested geometry has SRID {}", srid),
+        }
+    }
+}
+
+/// An issue found by [`validate_wkb`], with its location in the geometry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The kind of issue
+    pub kind: IssueKind,
+    /// The path to the nested geometry with the issue, as the index of the part at each level of
+    /// nesting. This is empty for the top-level geometry.
+    pub path: Vec<usize>,
+    /// The index of the ring with the issue, within a Polygon or Triangle
+    pub ring: Option<usize>,
+    /// The index of the coordinate with the issue, within a Point, LineString or ring
+    pub vertex: Option<usize>,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (path {:?}", self.kind, self.path)?;
+        if let Some(ring) = self.ring {
+            write!(f, ", ring {}", ring)?;
+        }
+        if let Some(vertex) = self.vertex {
+            write!(f, ", vertex {}", vertex)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Check the WKB geometry at the start of `buf` against the OGC Simple Features rules, returning
+/// every issue reported at `strictness`.
+///
+/// An empty list means the geometry is valid. Malformed WKB that cannot be walked, such as a
+/// truncated buffer, returns an error instead, as do collections nested more than
+/// [`MAX_NESTING_DEPTH`][crate::reader::MAX_NESTING_DEPTH] levels deep.
+///
+/// ```
+/// use wkb::validate::{validate_wkb, IssueKind, Strictness};
+///
+/// // A LineString with a single point
+/// let line_string = geo_types::LineString::from(vec![(1., 2.)]);
+/// let mut buf = Vec::new();
+/// wkb::writer::write_line_string(&mut buf, &line_string, Default::default()).unwrap();
+///
+/// let issues = validate_wkb(&buf, Strictness::Strict).unwrap();
+/// assert_eq!(issues[0].kind, IssueKind::TooFewPoints { found: 1, min: 2 });
+/// assert!(validate_wkb(&buf, Strictness::Lenient).unwrap().is_empty());
+/// ```
+pub fn validate_wkb(buf: &[u8], strictness: Strictness) -> WKBResult<Vec<ValidationIssue>> {
+    let mut validator = Validator {
+        strictness,
+        path: vec![],
+        issues: vec![],
+    };
+    validator.geometry(buf, 0, None)?;
+    Ok(validator.issues)
+}
+
+/// Walks the structure of a WKB geometry, collecting issues
+struct Validator {
+    strictness: Strictness,
+    /// The path to the geometry being walked
+    path: Vec<usize>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator {
+    fn report(&mut self, kind: IssueKind, ring: Option<usize>, vertex: Option<usize>) {
+        if kind.strictness() <= self.strictness {
+            self.issues.push(ValidationIssue {
+                kind,
+                path: self.path.clone(),
+                ring,
+                vertex,
+            });
+        }
+    }
+
+    /// Validate the geometry starting at `offset`, nested in a geometry of type `parent` if any,
+    /// returning its size
+    fn geometry(&mut self, buf: &[u8], offset: u64, parent: Option<WKBType>) -> WKBResult<u64> {
+        // The path has one index for every level of nesting
+        check_depth(self.path.len(), offset)?;
+        let (byte_order, wkb_type) = read_header(buf, offset)?;
+        let srid = read_srid(buf, byte_order, offset)?;
+        let dim = wkb_type.dimension();
+
+        if let Some(parent) = parent {
+            if parent.dimension() != dim {
+                let kind = IssueKind::DimensionMismatch {
+                    parent: parent.dimension(),
+                    child: dim,
+                };
+                self.report(kind, None, None);
+            }
+            if let Some(expected) = part_type(&parent) {
+                if discriminant(&expected) != discriminant(&wkb_type) {
+                    let kind = IssueKind::UnexpectedPartType {
+                        expected,
+                        found: wkb_type,
+                    };
+                    self.report(kind, None, None);
+                }
+            }
+            if let Some(srid) = srid {
+                self.report(IssueKind::NestedSrid { srid }, None, None);
+            }
+        }
+
+        let mut body_offset = offset + 1 + 4;
+        if srid.is_some() {
+            body_offset += 4;
+        }
+        let coords = Coords {
+            buf,
+            byte_order,
+            dim,
+        };
+
+        let body_size = match wkb_type {
+            WKBType::Point(_) => {
+                let coord = coords.read(body_offset, 0)?;
+                let values = &coord[..dim.size()];
+                // A Point with all NaN values is empty
+                if values.iter().any(|v| v.is_nan()) && !values.iter().all(|v| v.is_nan()) {
+                    self.report(IssueKind::NanCoordinate, None, Some(0));
+                }
+                dim.size() as u64 * 8
+            }
+            WKBType::LineString(_) | WKBType::CircularString(_) => {
+                let num_points = self.coord_sequence(&coords, body_offset, None)?;
+                if matches!(wkb_type, WKBType::LineString(_)) && num_points == 1 {
+                    let kind = IssueKind::TooFewPoints { found: 1, min: 2 };
+                    self.report(kind, None, None);
+                }
+                4 + num_points as u64 * dim.size() as u64 * 8
+            }
+            WKBType::Polygon(_) | WKBType::Triangle(_) => {
+                let is_triangle = matches!(wkb_type, WKBType::Triangle(_));
+                let num_rings = read_u32_at(buf, byte_order, body_offset)? as usize;
+                let mut ring_offset = body_offset + 4;
+                for i in 0..num_rings {
+                    let num_points = self
+                        .ring(&coords, ring_offset, i, is_triangle)
+                        .map_err(|err| err.in_part(i))?;
+                    ring_offset += 4 + num_points as u64 * dim.size() as u64 * 8;
+                }
+                ring_offset - body_offset
+            }
+            // Every other type is a sequence of nested geometries, each with its own header
+            _ => {
+                let num_parts = read_u32_at(buf, byte_order, body_offset)? as usize;
+                let mut part_offset = body_offset + 4;
+                for i in 0..num_parts {
+                    self.path.push(i);
+                    let part_size = self.geometry(buf, part_offset, Some(wkb_type));
+                    self.path.pop();
+                    part_offset += part_size.map_err(|err| err.in_part(i))?;
+                }
+                part_offset - body_offset
+            }
+        };
+        Ok(body_offset - offset + body_size)
+    }
+
+    /// Validate the ring with index `ring` starting at `offset`, returning its number of points
+    ///
+    /// The ring of a Triangle must have exactly four points.
+    fn ring(
+        &mut self,
+        coords: &Coords,
+        offset: u64,
+        ring: usize,
+        is_triangle: bool,
+    ) -> WKBResult<usize> {
+        let num_points = self.coord_sequence(coords, offset, Some(ring))?;
+        if num_points < 4 {
+            let kind = IssueKind::TooFewPoints {
+                found: num_points,
+                min: 4,
+            };
+            self.report(kind, Some(ring), None);
+        } else if is_triangle && num_points > 4 {
+            let kind = IssueKind::TooManyPoints {
+                found: num_points,
+                max: 4,
+            };
+            self.report(kind, Some(ring), None);
+        }
+        if num_points >= 2 {
+            let dim_size = coords.dim.size();
+            let first = coords.read(offset + 4, 0)?;
+            let last = coords.read(offset + 4, num_points - 1)?;
+            let (first, last) = (&first[..dim_size], &last[..dim_size]);
+            // NaN values were already reported, and never compare equal
+            let has_nan = first.iter().chain(last).any(|v| v.is_nan());
+            if !has_nan && first != last {
+                self.report(IssueKind::UnclosedRing, Some(ring), None);
+            }
+        }
+        Ok(num_points)
+    }
+
+    /// Validate a count followed by that many coordinates, returning the count
+    fn coord_sequence(
+        &mut self,
+        coords: &Coords,
+        offset: u64,
+        ring: Option<usize>,
+    ) -> WKBResult<usize> {
+        let num_points = read_u32_at(coords.buf, coords.byte_order, offset)? as usize;
+        let coord_size = coords.dim.size() as u64 * 8;
+        check_bounds(coords.buf, offset + 4, num_points as u64 * coord_size)?;
+        for vertex in 0..num_points {
+            let coord = coords.read(offset + 4, vertex)?;
+            if coord[..coords.dim.size()].iter().any(|v| v.is_nan()) {
+                self.report(IssueKind::NanCoordinate, ring, Some(vertex));
+            }
+        }
+        Ok(num_points)
+    }
+}
+
+/// The coordinates of a single geometry
+struct Coords<'a> {
+    buf: &'a [u8],
+    byte_order: Endianness,
+    dim: WKBDimension,
+}
+
+impl Coords<'_> {
+    /// Read the coordinate with index `i` of the coordinates starting at `offset`
+    fn read(&self, offset: u64, i: usize) -> WKBResult<[f64; 4]> {
+        let coord_size = self.dim.size() as u64 * 8;
+        let coord_offset = offset + i as u64 * coord_size;
+        check_bounds(self.buf, coord_offset, coord_size)?;
+        let bytes = &self.buf[coord_offset as usize..(coord_offset + coord_size) as usize];
+        let mut values = [f64::NAN; 4];
+        for (value, bytes) in values.iter_mut().zip(bytes.chunks_exact(8)) {
+            *value = match self.byte_order {
+                Endianness::BigEndian => BigEndian::read_f64(bytes),
+                Endianness::LittleEndian => LittleEndian::read_f64(bytes),
+            };
+        }
+        Ok(values)
+    }
+}
+
+/// The type every part of a geometry of type `parent` must have, if there is one
+fn part_type(parent: &WKBType) -> Option<WKBType> {
+    match *parent {
+        WKBType::MultiPoint(dim) => Some(WKBType::Point(dim)),
+        WKBType::MultiLineString(dim) => Some(WKBType::LineString(dim)),
+        WKBType::MultiPolygon(dim) | WKBType::PolyhedralSurface(dim) => Some(WKBType::Polygon(dim)),
+        WKBType::Tin(dim) => Some(WKBType::Triangle(dim)),
+        _ => None,
+    }
+}
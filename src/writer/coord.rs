@@ -4,17 +4,22 @@ use byteorder::{ByteOrder, WriteBytesExt};
 use geo_traits::CoordTrait;
 
 use crate::error::WKBResult;
+use crate::writer::WriteOptions;
 
 /// Write a coordinate to a Writer encoded as WKB
+///
+/// Each value is converted to `f64` as it is written. Only x and y are written if `options` drop
+/// z and m values.
 pub(crate) fn write_coord<B: ByteOrder>(
     writer: &mut impl Write,
-    coord: &impl CoordTrait<T = f64>,
+    coord: &impl CoordTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    for i in 0..coord.dim().size() {
+    for i in 0..options.coord_size(coord.dim()) {
         // # Safety
         // We just checked the number of dimensions in this coord
         let val = unsafe { coord.nth_unchecked(i) };
-        writer.write_f64::<B>(val)?;
+        writer.write_f64::<B>(val.into())?;
     }
 
     Ok(())
@@ -0,0 +1,219 @@
+use crate::common::WKBType;
+use crate::error::WKBResult;
+use crate::reader::{
+    CompoundCurve, Curve, CurvePolygon, CurvedWkb, MultiCurve, MultiSurface, Surface,
+};
+use crate::writer::coord::write_coord;
+use crate::writer::options::WriteOptions;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
+use crate::writer::{
+    geometry_wkb_size, line_string_wkb_size, polygon_wkb_size, write_geometry, write_line_string,
+    write_polygon,
+};
+use crate::Endianness;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
+use geo_traits::LineStringTrait;
+use std::io::Write;
+
+/// The number of bytes this CircularString will take up when encoded as WKB
+pub fn circular_string_wkb_size(
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    line_string_wkb_size(geom, options)
+}
+
+/// Write a CircularString geometry to a Writer encoded as WKB
+///
+/// The coordinates of `geom` are the control points of the CircularString.
+pub fn write_circular_string(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    // Byte order
+    writer.write_u8(options.endianness.into())?;
+
+    // Content
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_circular_string_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_circular_string_content::<BigEndian>(writer, geom, options),
+    }
+}
+
+fn write_circular_string_content<B: ByteOrder>(
+    writer: &mut impl Write,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    let wkb_type = WKBType::CircularString(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
+
+    // numPoints
+    writer.write_u32::<B>(geom.num_coords().try_into().unwrap())?;
+
+    for coord in geom.coords() {
+        write_coord::<B>(writer, &coord, options)?;
+    }
+
+    Ok(())
+}
+
+/// The number of bytes this geometry will take up when encoded as WKB
+pub fn curved_geometry_wkb_size(geom: &CurvedWkb, options: WriteOptions) -> usize {
+    match geom {
+        CurvedWkb::Linear(g) => geometry_wkb_size(g, options),
+        CurvedWkb::CircularString(g) => circular_string_wkb_size(g, options),
+        CurvedWkb::CompoundCurve(g) => parts_wkb_size(g.segments(), curve_wkb_size, options),
+        CurvedWkb::CurvePolygon(g) => parts_wkb_size(g.rings(), curve_wkb_size, options),
+        CurvedWkb::MultiCurve(g) => parts_wkb_size(g.curves(), curve_wkb_size, options),
+        CurvedWkb::MultiSurface(g) => parts_wkb_size(g.surfaces(), surface_wkb_size, options),
+        CurvedWkb::GeometryCollection(g) => {
+            parts_wkb_size(g.geometries(), curved_geometry_wkb_size, options)
+        }
+    }
+}
+
+/// Write a geometry that may be one of the SQL/MM curve types to a Writer encoded as WKB
+///
+/// Linear geometries are written as with [`write_geometry`].
+pub fn write_curved_geometry(
+    writer: &mut impl Write,
+    geom: &CurvedWkb,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    match geom {
+        CurvedWkb::Linear(g) => write_geometry(writer, g, options),
+        CurvedWkb::CircularString(g) => write_circular_string(writer, g, options),
+        CurvedWkb::CompoundCurve(g) => write_compound_curve(writer, g, options),
+        CurvedWkb::CurvePolygon(g) => write_curve_polygon(writer, g, options),
+        CurvedWkb::MultiCurve(g) => write_multi_curve(writer, g, options),
+        CurvedWkb::MultiSurface(g) => write_multi_surface(writer, g, options),
+        CurvedWkb::GeometryCollection(g) => write_parts(
+            writer,
+            WKBType::GeometryCollection(g.dimension()),
+            g.geometries(),
+            |writer, geom, options| write_curved_geometry(writer, geom, options),
+            options,
+        ),
+    }
+}
+
+fn curve_wkb_size(geom: &Curve, options: WriteOptions) -> usize {
+    match geom {
+        Curve::LineString(g) => line_string_wkb_size(g, options),
+        Curve::CircularString(g) => circular_string_wkb_size(g, options),
+        Curve::CompoundCurve(g) => parts_wkb_size(g.segments(), curve_wkb_size, options),
+    }
+}
+
+fn surface_wkb_size(geom: &Surface, options: WriteOptions) -> usize {
+    match geom {
+        Surface::Polygon(g) => polygon_wkb_size(g, options),
+        Surface::CurvePolygon(g) => parts_wkb_size(g.rings(), curve_wkb_size, options),
+    }
+}
+
+/// The size of a geometry made up of a header, a part count and each nested part
+fn parts_wkb_size<T>(
+    parts: &[T],
+    part_size: impl Fn(&T, WriteOptions) -> usize,
+    options: WriteOptions,
+) -> usize {
+    let header = header_wkb_size(options.flavor) + 4;
+    parts
+        .iter()
+        .fold(header, |acc, part| acc + part_size(part, options.nested()))
+}
+
+fn write_curve(writer: &mut impl Write, geom: &Curve, options: WriteOptions) -> WKBResult<()> {
+    match geom {
+        Curve::LineString(g) => write_line_string(writer, g, options),
+        Curve::CircularString(g) => write_circular_string(writer, g, options),
+        Curve::CompoundCurve(g) => write_compound_curve(writer, g, options),
+    }
+}
+
+fn write_surface(writer: &mut impl Write, geom: &Surface, options: WriteOptions) -> WKBResult<()> {
+    match geom {
+        Surface::Polygon(g) => write_polygon(writer, g, options),
+        Surface::CurvePolygon(g) => write_curve_polygon(writer, g, options),
+    }
+}
+
+fn write_compound_curve(
+    writer: &mut impl Write,
+    geom: &CompoundCurve,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    let wkb_type = WKBType::CompoundCurve(geom.dimension());
+    write_parts(writer, wkb_type, geom.segments(), write_curve, options)
+}
+
+fn write_curve_polygon(
+    writer: &mut impl Write,
+    geom: &CurvePolygon,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    let wkb_type = WKBType::CurvePolygon(geom.dimension());
+    write_parts(writer, wkb_type, geom.rings(), write_curve, options)
+}
+
+fn write_multi_curve(
+    writer: &mut impl Write,
+    geom: &MultiCurve,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    let wkb_type = WKBType::MultiCurve(geom.dimension());
+    write_parts(writer, wkb_type, geom.curves(), write_curve, options)
+}
+
+fn write_multi_surface(
+    writer: &mut impl Write,
+    geom: &MultiSurface,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    let wkb_type = WKBType::MultiSurface(geom.dimension());
+    write_parts(writer, wkb_type, geom.surfaces(), write_surface, options)
+}
+
+/// Write a geometry made up of a header, a part count and each nested part
+fn write_parts<W: Write, T>(
+    writer: &mut W,
+    wkb_type: WKBType,
+    parts: &[T],
+    write_part: impl Fn(&mut W, &T, WriteOptions) -> WKBResult<()>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    // Byte order
+    writer.write_u8(options.endianness.into())?;
+
+    // Geometry code and number of parts
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_parts_header::<LittleEndian>(writer, wkb_type, parts.len(), options)?
+        }
+        Endianness::BigEndian => {
+            write_parts_header::<BigEndian>(writer, wkb_type, parts.len(), options)?
+        }
+    }
+
+    for part in parts {
+        write_part(writer, part, options.nested())?;
+    }
+
+    Ok(())
+}
+
+fn write_parts_header<B: ByteOrder>(
+    writer: &mut impl Write,
+    wkb_type: WKBType,
+    num_parts: usize,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
+    writer.write_u32::<B>(num_parts.try_into().unwrap())?;
+    Ok(())
+}
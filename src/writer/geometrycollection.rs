@@ -1,17 +1,22 @@
 use crate::common::WKBType;
 use crate::error::WKBResult;
 use crate::writer::geometry::{geometry_wkb_size, write_geometry};
+use crate::writer::options::WriteOptions;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::GeometryCollectionTrait;
 use std::io::Write;
 
 /// The number of bytes this GeometryCollection will take up when encoded as WKB
-pub fn geometry_collection_wkb_size(geom: &impl GeometryCollectionTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn geometry_collection_wkb_size(
+    geom: &impl GeometryCollectionTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    let mut sum = header_wkb_size(options.flavor) + 4;
 
     for inner_geom in geom.geometries() {
-        sum += geometry_wkb_size(&inner_geom);
+        sum += geometry_wkb_size(&inner_geom, options.nested());
     }
 
     sum
@@ -20,36 +25,36 @@ pub fn geometry_collection_wkb_size(geom: &impl GeometryCollectionTrait<T = f64>
 /// Write a GeometryCollection geometry to a Writer encoded as WKB
 pub fn write_geometry_collection(
     writer: &mut impl Write,
-    geom: &impl GeometryCollectionTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl GeometryCollectionTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
+    match options.endianness {
         Endianness::LittleEndian => {
-            write_geometry_collection_content::<LittleEndian>(writer, geom, endianness)
+            write_geometry_collection_content::<LittleEndian>(writer, geom, options)
         }
         Endianness::BigEndian => {
-            write_geometry_collection_content::<BigEndian>(writer, geom, endianness)
+            write_geometry_collection_content::<BigEndian>(writer, geom, options)
         }
     }
 }
 
 fn write_geometry_collection_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl GeometryCollectionTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl GeometryCollectionTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     let wkb_type = WKBType::GeometryCollection(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numGeometries
     writer.write_u32::<B>(geom.num_geometries().try_into().unwrap())?;
 
     for inner_geom in geom.geometries() {
-        write_geometry(writer, &inner_geom, endianness)?;
+        write_geometry(writer, &inner_geom, options.nested())?;
     }
 
     Ok(())
@@ -0,0 +1,54 @@
+use std::fmt;
+use std::io;
+
+use geo_traits::GeometryTrait;
+
+use crate::error::WKBResult;
+use crate::writer::options::WriteOptions;
+use crate::writer::{geometry_wkb_size, write_geometry};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Adapts a [`fmt::Write`] into an [`io::Write`] that writes each byte as two upper-case hex digits
+struct HexWriter<'a, W: fmt::Write>(&'a mut W);
+
+impl<W: fmt::Write> io::Write for HexWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for byte in buf {
+            for digit in [byte >> 4, byte & 0xf] {
+                self.0
+                    .write_char(HEX_DIGITS[digit as usize] as char)
+                    .map_err(io::Error::other)?;
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Write a Geometry to a [`fmt::Write`] encoded as hex WKB
+///
+/// This writes the same bytes as [`write_geometry`], as upper-case hex digits. With
+/// [`WKBFlavor::Extended`][crate::WKBFlavor::Extended] this writes hex EWKB as used by PostGIS.
+pub fn write_geometry_hex(
+    writer: &mut impl fmt::Write,
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    write_geometry(&mut HexWriter(writer), geom, options)
+}
+
+/// Encode a Geometry as a hex WKB string
+///
+/// See [`write_geometry_hex`].
+pub fn geometry_to_hex(
+    geom: &impl GeometryTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<String> {
+    let mut out = String::with_capacity(2 * geometry_wkb_size(geom, options));
+    write_geometry_hex(&mut out, geom, options)?;
+    Ok(out)
+}
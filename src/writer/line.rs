@@ -3,14 +3,14 @@ use std::io::Write;
 use geo_traits::{LineStringTrait, LineTrait};
 
 use crate::error::WKBResult;
+use crate::writer::options::WriteOptions;
 use crate::writer::{line_string_wkb_size, write_line_string};
-use crate::Endianness;
 
 /// A wrapper around an impl LineTrait to provide LineStringTrait
-struct LineWrapper<'a, G: LineTrait<T = f64>>(&'a G);
+pub(crate) struct LineWrapper<'a, G: LineTrait<T: Into<f64>>>(pub(crate) &'a G);
 
-impl<'a, G: LineTrait<T = f64>> LineStringTrait for LineWrapper<'a, G> {
-    type T = f64;
+impl<'a, G: LineTrait<T: Into<f64>>> LineStringTrait for LineWrapper<'a, G> {
+    type T = G::T;
     type CoordType<'b>
         = G::CoordType<'a>
     where
@@ -35,15 +35,15 @@ impl<'a, G: LineTrait<T = f64>> LineStringTrait for LineWrapper<'a, G> {
 }
 
 /// The number of bytes this Line will take up when encoded as WKB
-pub fn line_wkb_size(geom: &impl LineTrait<T = f64>) -> usize {
-    line_string_wkb_size(&LineWrapper(geom))
+pub fn line_wkb_size(geom: &impl LineTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    line_string_wkb_size(&LineWrapper(geom), options)
 }
 
 /// Write a Line geometry to a Writer encoded as WKB
 pub fn write_line(
     writer: &mut impl Write,
-    geom: &impl LineTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl LineTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    write_line_string(writer, &LineWrapper(geom), endianness)
+    write_line_string(writer, &LineWrapper(geom), options)
 }
@@ -1,15 +1,20 @@
 use crate::common::WKBType;
 use crate::error::WKBResult;
 use crate::writer::coord::write_coord;
+use crate::writer::options::WriteOptions;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::LineStringTrait;
 use std::io::Write;
 
 /// The number of bytes this LineString will take up when encoded as WKB
-pub fn line_string_wkb_size(geom: &impl LineStringTrait<T = f64>) -> usize {
-    let header = 1 + 4 + 4;
-    let each_coord = geom.dim().size() * 8;
+pub fn line_string_wkb_size(
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    let header = header_wkb_size(options.flavor) + 4;
+    let each_coord = options.coord_size(geom.dim()) * 8;
     let all_coords = geom.num_coords() * each_coord;
     header + all_coords
 }
@@ -17,25 +22,28 @@ pub fn line_string_wkb_size(geom: &impl LineStringTrait<T = f64>) -> usize {
 /// Write a LineString geometry to a Writer encoded as WKB
 pub fn write_line_string(
     writer: &mut impl Write,
-    geom: &impl LineStringTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into()).unwrap();
+    writer.write_u8(options.endianness.into()).unwrap();
 
     // Content
-    match endianness {
-        Endianness::LittleEndian => write_line_string_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_line_string_content::<BigEndian>(writer, geom),
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_line_string_content::<LittleEndian>(writer, geom, options)
+        }
+        Endianness::BigEndian => write_line_string_content::<BigEndian>(writer, geom, options),
     }
 }
 
 fn write_line_string_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl LineStringTrait<T = f64>,
+    geom: &impl LineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     let wkb_type = WKBType::LineString(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numPoints
     writer
@@ -43,7 +51,7 @@ fn write_line_string_content<B: ByteOrder>(
         .unwrap();
 
     for coord in geom.coords() {
-        write_coord::<B>(writer, &coord)?;
+        write_coord::<B>(writer, &coord, options)?;
     }
 
     Ok(())
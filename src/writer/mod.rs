@@ -1,26 +1,43 @@
 //! Write geometries to Well-Known Binary encoding.
 
 mod coord;
+mod curve;
 mod geometry;
 mod geometrycollection;
+mod hex;
 mod line;
 mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod options;
 mod point;
 mod polygon;
+mod polyhedralsurface;
 mod rect;
+mod tin;
 mod triangle;
+mod util;
 
+pub use curve::{
+    circular_string_wkb_size, curved_geometry_wkb_size, write_circular_string,
+    write_curved_geometry,
+};
 pub use geometry::{geometry_wkb_size, write_geometry};
 pub use geometrycollection::{geometry_collection_wkb_size, write_geometry_collection};
+pub use hex::{geometry_to_hex, write_geometry_hex};
+pub(crate) use line::LineWrapper;
 pub use line::{line_wkb_size, write_line};
 pub use linestring::{line_string_wkb_size, write_line_string};
 pub use multilinestring::{multi_line_string_wkb_size, write_multi_line_string};
 pub use multipoint::{multi_point_wkb_size, write_multi_point};
 pub use multipolygon::{multi_polygon_wkb_size, write_multi_polygon};
+pub use options::{EmptyPointEncoding, WriteOptions};
 pub use point::{point_wkb_size, write_point};
 pub use polygon::{polygon_wkb_size, write_polygon};
+pub use polyhedralsurface::{polyhedral_surface_wkb_size, write_polyhedral_surface};
+pub(crate) use rect::RectWrapper;
 pub use rect::{rect_wkb_size, write_rect};
+pub use tin::{tin_wkb_size, write_tin};
+pub(crate) use triangle::TriangleWrapper;
 pub use triangle::{triangle_wkb_size, write_triangle};
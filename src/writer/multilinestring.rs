@@ -1,16 +1,21 @@
 use crate::common::WKBType;
 use crate::error::WKBResult;
 use crate::writer::linestring::{line_string_wkb_size, write_line_string};
+use crate::writer::options::WriteOptions;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::MultiLineStringTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiLineString will take up when encoded as WKB
-pub fn multi_line_string_wkb_size(geom: &impl MultiLineStringTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn multi_line_string_wkb_size(
+    geom: &impl MultiLineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    let mut sum = header_wkb_size(options.flavor) + 4;
     for line_string in geom.line_strings() {
-        sum += line_string_wkb_size(&line_string);
+        sum += line_string_wkb_size(&line_string, options.nested());
     }
 
     sum
@@ -19,36 +24,36 @@ pub fn multi_line_string_wkb_size(geom: &impl MultiLineStringTrait<T = f64>) ->
 /// Write a MultiLineString geometry to a Writer encoded as WKB
 pub fn write_multi_line_string(
     writer: &mut impl Write,
-    geom: &impl MultiLineStringTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiLineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
+    match options.endianness {
         Endianness::LittleEndian => {
-            write_multi_line_string_content::<LittleEndian>(writer, geom, endianness)
+            write_multi_line_string_content::<LittleEndian>(writer, geom, options)
         }
         Endianness::BigEndian => {
-            write_multi_line_string_content::<BigEndian>(writer, geom, endianness)
+            write_multi_line_string_content::<BigEndian>(writer, geom, options)
         }
     }
 }
 
 fn write_multi_line_string_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl MultiLineStringTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiLineStringTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     let wkb_type = WKBType::MultiLineString(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numPoints
     writer.write_u32::<B>(geom.num_line_strings().try_into().unwrap())?;
 
     for line_string in geom.line_strings() {
-        write_line_string(writer, &line_string, endianness)?;
+        write_line_string(writer, &line_string, options.nested())?;
     }
 
     Ok(())
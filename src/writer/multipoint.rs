@@ -1,47 +1,58 @@
 use crate::common::WKBType;
 use crate::error::WKBResult;
-use crate::writer::point::{point_wkb_size, write_point};
+use crate::writer::options::{EmptyPointEncoding, WriteOptions};
+use crate::writer::point::write_point;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::MultiPointTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiPoint will take up when encoded as WKB
-pub fn multi_point_wkb_size(geom: &impl MultiPointTrait<T = f64>) -> usize {
-    1 + 4 + 4 + (geom.num_points() * point_wkb_size(geom.dim()))
+pub fn multi_point_wkb_size(
+    geom: &impl MultiPointTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    let point_size = header_wkb_size(options.nested().flavor) + options.coord_size(geom.dim()) * 8;
+    header_wkb_size(options.flavor) + 4 + (geom.num_points() * point_size)
 }
 
 /// Write a MultiPoint geometry to a Writer encoded as WKB
 pub fn write_multi_point(
     writer: &mut impl Write,
-    geom: &impl MultiPointTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiPointTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
+    match options.endianness {
         Endianness::LittleEndian => {
-            write_multi_point_content::<LittleEndian>(writer, geom, endianness)
+            write_multi_point_content::<LittleEndian>(writer, geom, options)
         }
-        Endianness::BigEndian => write_multi_point_content::<BigEndian>(writer, geom, endianness),
+        Endianness::BigEndian => write_multi_point_content::<BigEndian>(writer, geom, options),
     }
 }
 
 fn write_multi_point_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl MultiPointTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiPointTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     let wkb_type = WKBType::MultiPoint(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numPoints
     writer.write_u32::<B>(geom.num_points().try_into().unwrap())?;
 
+    // A MultiPoint can only contain Points, so empty Points are always written as NaN
+    let point_options = WriteOptions {
+        empty_point: EmptyPointEncoding::Nan,
+        ..options.nested()
+    };
     for point in geom.points() {
-        write_point(writer, &point, endianness)?;
+        write_point(writer, &point, point_options)?;
     }
 
     Ok(())
@@ -1,16 +1,21 @@
-use crate::common::WKBType;
+use crate::common::{WKBDimension, WKBType};
 use crate::error::WKBResult;
-use crate::writer::polygon::{polygon_wkb_size, write_polygon};
+use crate::writer::options::WriteOptions;
+use crate::writer::polygon::{polygon_wkb_size, write_polygon_with_type};
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::MultiPolygonTrait;
 use std::io::Write;
 
 /// The number of bytes this MultiPolygon will take up when encoded as WKB
-pub fn multi_polygon_wkb_size(geom: &impl MultiPolygonTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn multi_polygon_wkb_size(
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    let mut sum = header_wkb_size(options.flavor) + 4;
     for polygon in geom.polygons() {
-        sum += polygon_wkb_size(&polygon);
+        sum += polygon_wkb_size(&polygon, options.nested());
     }
 
     sum
@@ -19,34 +24,60 @@ pub fn multi_polygon_wkb_size(geom: &impl MultiPolygonTrait<T = f64>) -> usize {
 /// Write a MultiPolygon geometry to a Writer encoded as WKB
 pub fn write_multi_polygon(
     writer: &mut impl Write,
-    geom: &impl MultiPolygonTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    write_multi_polygon_with_types(
+        writer,
+        geom,
+        WKBType::MultiPolygon,
+        WKBType::Polygon,
+        options,
+    )
+}
+
+/// Write a geometry with the layout of a MultiPolygon, such as a PolyhedralSurface or TIN, with
+/// the given geometry type and part type
+pub(crate) fn write_multi_polygon_with_types(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    wkb_type: fn(WKBDimension) -> WKBType,
+    polygon_type: fn(WKBDimension) -> WKBType,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
-        Endianness::LittleEndian => {
-            write_multi_polygon_content::<LittleEndian>(writer, geom, endianness)
+    match options.endianness {
+        Endianness::LittleEndian => write_multi_polygon_content::<LittleEndian>(
+            writer,
+            geom,
+            wkb_type,
+            polygon_type,
+            options,
+        ),
+        Endianness::BigEndian => {
+            write_multi_polygon_content::<BigEndian>(writer, geom, wkb_type, polygon_type, options)
         }
-        Endianness::BigEndian => write_multi_polygon_content::<BigEndian>(writer, geom, endianness),
     }
 }
 
 fn write_multi_polygon_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl MultiPolygonTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    wkb_type: fn(WKBDimension) -> WKBType,
+    polygon_type: fn(WKBDimension) -> WKBType,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    let wkb_type = WKBType::MultiPolygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    let wkb_type = wkb_type(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numPolygons
     writer.write_u32::<B>(geom.num_polygons().try_into().unwrap())?;
 
     for polygon in geom.polygons() {
-        write_polygon(writer, &polygon, endianness)?;
+        write_polygon_with_type(writer, &polygon, polygon_type, options.nested())?;
     }
 
     Ok(())
@@ -0,0 +1,75 @@
+use geo_traits::Dimensions;
+
+use crate::common::{WKBDimension, WKBFlavor, WKBType};
+use crate::Endianness;
+
+/// How to write an empty Point, which WKB has no encoding for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmptyPointEncoding {
+    /// A Point with NaN coordinates, as written by PostGIS and GEOS
+    #[default]
+    Nan,
+    /// A MultiPoint with no points
+    ///
+    /// The Points of a MultiPoint are always written with NaN coordinates, since a MultiPoint can
+    /// only contain Points.
+    EmptyMultiPoint,
+}
+
+/// Options for writing geometries as WKB
+///
+/// ```
+/// use wkb::writer::{write_geometry, WriteOptions};
+/// use wkb::{Endianness, WKBFlavor};
+///
+/// let point = geo_types::Point::new(1., 2.);
+/// let options = WriteOptions {
+///     endianness: Endianness::BigEndian,
+///     flavor: WKBFlavor::Extended { srid: Some(4326) },
+///     ..Default::default()
+/// };
+/// let mut buf = Vec::new();
+/// write_geometry(&mut buf, &geo_types::Geometry::Point(point), options).unwrap();
+/// assert_eq!(buf[..9], [0x00, 0x20, 0x00, 0x00, 0x01, 0x00, 0x00, 0x10, 0xe6]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriteOptions {
+    /// The byte order to write
+    pub endianness: Endianness,
+    /// The flavor of WKB to write, including any SRID
+    pub flavor: WKBFlavor,
+    /// How to write an empty Point
+    pub empty_point: EmptyPointEncoding,
+    /// Whether to write Triangles as Polygons instead of as ISO WKB Triangles
+    ///
+    /// Rects and Lines have no WKB type of their own, so they are always written as Polygons and
+    /// LineStrings.
+    pub triangle_as_polygon: bool,
+}
+
+impl WriteOptions {
+    /// The options to use for parts nested in a geometry written with these options
+    pub(crate) fn nested(&self) -> Self {
+        Self {
+            flavor: self.flavor.nested(),
+            ..*self
+        }
+    }
+
+    /// The number of values written for each coordinate of a geometry with dimension `dim`
+    pub(crate) fn coord_size(&self, dim: Dimensions) -> usize {
+        match self.flavor {
+            WKBFlavor::Ogc11 => 2,
+            _ => dim.size(),
+        }
+    }
+
+    /// The type to write a Triangle as
+    pub(crate) fn triangle_type(&self) -> fn(WKBDimension) -> WKBType {
+        if self.triangle_as_polygon || matches!(self.flavor, WKBFlavor::Ogc11) {
+            WKBType::Polygon
+        } else {
+            WKBType::Triangle
+        }
+    }
+}
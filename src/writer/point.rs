@@ -1,6 +1,8 @@
 use crate::common::WKBType;
 use crate::error::WKBResult;
 use crate::writer::coord::write_coord;
+use crate::writer::options::{EmptyPointEncoding, WriteOptions};
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use core::f64;
@@ -8,42 +10,55 @@ use geo_traits::PointTrait;
 use std::io::Write;
 
 /// The number of bytes this Point will take up when encoded as WKB
-pub fn point_wkb_size(dim: geo_traits::Dimensions) -> usize {
-    let header = 1 + 4;
-    let coords = dim.size() * 8;
+pub fn point_wkb_size(geom: &impl PointTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    let header = header_wkb_size(options.flavor);
+    if geom.coord().is_none() && options.empty_point == EmptyPointEncoding::EmptyMultiPoint {
+        // numPoints
+        return header + 4;
+    }
+    let coords = options.coord_size(geom.dim()) * 8;
     header + coords
 }
 
 /// Write a Point geometry to a Writer encoded as WKB
 pub fn write_point(
     writer: &mut impl Write,
-    geom: &impl PointTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl PointTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order header
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
-        Endianness::LittleEndian => write_point_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_point_content::<BigEndian>(writer, geom),
+    match options.endianness {
+        Endianness::LittleEndian => write_point_content::<LittleEndian>(writer, geom, options),
+        Endianness::BigEndian => write_point_content::<BigEndian>(writer, geom, options),
     }
 }
 
 /// Write a Point geometry to a Writer encoded as WKB
 fn write_point_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl PointTrait<T = f64>,
+    geom: &impl PointTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    let wkb_type = WKBType::Point(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    let dim = geom.dim().try_into()?;
 
-    if let Some(coord) = geom.coord() {
-        write_coord::<B>(writer, &coord)?;
-    } else {
-        // Write POINT EMPTY as f64::NAN values
-        for _ in 0..geom.dim().size() {
-            writer.write_f64::<B>(f64::NAN)?;
+    match geom.coord() {
+        Some(coord) => {
+            write_geometry_code::<B>(writer, WKBType::Point(dim), options.flavor)?;
+            write_coord::<B>(writer, &coord, options)?;
+        }
+        None if options.empty_point == EmptyPointEncoding::EmptyMultiPoint => {
+            write_geometry_code::<B>(writer, WKBType::MultiPoint(dim), options.flavor)?;
+            writer.write_u32::<B>(0)?;
+        }
+        None => {
+            // Write POINT EMPTY as f64::NAN values
+            write_geometry_code::<B>(writer, WKBType::Point(dim), options.flavor)?;
+            for _ in 0..options.coord_size(geom.dim()) {
+                writer.write_f64::<B>(f64::NAN)?;
+            }
         }
     }
 
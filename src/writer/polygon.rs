@@ -1,16 +1,18 @@
-use crate::common::WKBType;
+use crate::common::{WKBDimension, WKBType};
 use crate::error::WKBResult;
 use crate::writer::coord::write_coord;
+use crate::writer::options::WriteOptions;
+use crate::writer::util::{header_wkb_size, write_geometry_code};
 use crate::Endianness;
 use byteorder::{BigEndian, ByteOrder, LittleEndian, WriteBytesExt};
 use geo_traits::{LineStringTrait, PolygonTrait};
 use std::io::Write;
 
 /// The number of bytes this Polygon will take up when encoded as WKB
-pub fn polygon_wkb_size(geom: &impl PolygonTrait<T = f64>) -> usize {
-    let mut sum = 1 + 4 + 4;
+pub fn polygon_wkb_size(geom: &impl PolygonTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    let mut sum = header_wkb_size(options.flavor) + 4;
 
-    let each_coord = geom.dim().size() * 8;
+    let each_coord = options.coord_size(geom.dim()) * 8;
 
     if let Some(ext_ring) = geom.exterior() {
         sum += 4 + (ext_ring.num_coords() * each_coord);
@@ -26,25 +28,41 @@ pub fn polygon_wkb_size(geom: &impl PolygonTrait<T = f64>) -> usize {
 /// Write a Polygon geometry to a Writer encoded as WKB
 pub fn write_polygon(
     writer: &mut impl Write,
-    geom: &impl PolygonTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl PolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    write_polygon_with_type(writer, geom, WKBType::Polygon, options)
+}
+
+/// Write a geometry with the layout of a Polygon, such as a Triangle, with the given type
+pub(crate) fn write_polygon_with_type(
+    writer: &mut impl Write,
+    geom: &impl PolygonTrait<T: Into<f64>>,
+    wkb_type: fn(WKBDimension) -> WKBType,
+    options: WriteOptions,
 ) -> WKBResult<()> {
     // Byte order
-    writer.write_u8(endianness.into())?;
+    writer.write_u8(options.endianness.into())?;
 
     // Content
-    match endianness {
-        Endianness::LittleEndian => write_polygon_content::<LittleEndian>(writer, geom),
-        Endianness::BigEndian => write_polygon_content::<BigEndian>(writer, geom),
+    match options.endianness {
+        Endianness::LittleEndian => {
+            write_polygon_content::<LittleEndian>(writer, geom, wkb_type, options)
+        }
+        Endianness::BigEndian => {
+            write_polygon_content::<BigEndian>(writer, geom, wkb_type, options)
+        }
     }
 }
 
 fn write_polygon_content<B: ByteOrder>(
     writer: &mut impl Write,
-    geom: &impl PolygonTrait<T = f64>,
+    geom: &impl PolygonTrait<T: Into<f64>>,
+    wkb_type: fn(WKBDimension) -> WKBType,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    let wkb_type = WKBType::Polygon(geom.dim().try_into()?);
-    writer.write_u32::<B>(wkb_type.into())?;
+    let wkb_type = wkb_type(geom.dim().try_into()?);
+    write_geometry_code::<B>(writer, wkb_type, options.flavor)?;
 
     // numRings
     let num_rings = if geom.exterior().is_some() {
@@ -58,7 +76,7 @@ fn write_polygon_content<B: ByteOrder>(
         writer.write_u32::<B>(ext_ring.num_coords().try_into().unwrap())?;
 
         for coord in ext_ring.coords() {
-            write_coord::<B>(writer, &coord)?;
+            write_coord::<B>(writer, &coord, options)?;
         }
     }
 
@@ -66,7 +84,7 @@ fn write_polygon_content<B: ByteOrder>(
         writer.write_u32::<B>(int_ring.num_coords().try_into().unwrap())?;
 
         for coord in int_ring.coords() {
-            write_coord::<B>(writer, &coord)?;
+            write_coord::<B>(writer, &coord, options)?;
         }
     }
 
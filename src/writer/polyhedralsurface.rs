@@ -0,0 +1,32 @@
+use crate::common::WKBType;
+use crate::error::WKBResult;
+use crate::writer::multi_polygon_wkb_size;
+use crate::writer::multipolygon::write_multi_polygon_with_types;
+use crate::writer::options::WriteOptions;
+use geo_traits::MultiPolygonTrait;
+use std::io::Write;
+
+/// The number of bytes this PolyhedralSurface will take up when encoded as WKB
+pub fn polyhedral_surface_wkb_size(
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> usize {
+    multi_polygon_wkb_size(geom, options)
+}
+
+/// Write a PolyhedralSurface geometry to a Writer encoded as WKB
+///
+/// Each polygon of `geom` is written as a patch of the PolyhedralSurface.
+pub fn write_polyhedral_surface(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    write_multi_polygon_with_types(
+        writer,
+        geom,
+        WKBType::PolyhedralSurface,
+        WKBType::Polygon,
+        options,
+    )
+}
@@ -3,10 +3,10 @@ use std::io::Write;
 use geo_traits::{CoordTrait, LineStringTrait, PolygonTrait, RectTrait};
 
 use crate::error::WKBResult;
+use crate::writer::options::WriteOptions;
 use crate::writer::{polygon_wkb_size, write_polygon};
-use crate::Endianness;
 
-struct Coord2D {
+pub(crate) struct Coord2D {
     x: f64,
     y: f64,
 }
@@ -35,10 +35,11 @@ impl CoordTrait for Coord2D {
     }
 }
 
-/// A wrapper around an impl RectTrait to provide LineStringTrait and PolygonTrait
-struct RectWrapper<'a, G: RectTrait<T = f64>>(&'a G);
+/// A wrapper around an impl RectTrait to provide LineStringTrait and PolygonTrait, with its
+/// coordinates converted to `f64`
+pub(crate) struct RectWrapper<'a, G: RectTrait<T: Into<f64>>>(pub(crate) &'a G);
 
-impl<'a, G: RectTrait<T = f64>> LineStringTrait for &'a RectWrapper<'a, G> {
+impl<'a, G: RectTrait<T: Into<f64>>> LineStringTrait for &'a RectWrapper<'a, G> {
     type T = f64;
     type CoordType<'b>
         = Coord2D
@@ -60,31 +61,31 @@ impl<'a, G: RectTrait<T = f64>> LineStringTrait for &'a RectWrapper<'a, G> {
 
         match i {
             0 => Coord2D {
-                x: min_coord.x(),
-                y: min_coord.y(),
+                x: min_coord.x().into(),
+                y: min_coord.y().into(),
             },
             1 => Coord2D {
-                x: min_coord.x(),
-                y: max_coord.y(),
+                x: min_coord.x().into(),
+                y: max_coord.y().into(),
             },
             2 => Coord2D {
-                x: max_coord.x(),
-                y: max_coord.y(),
+                x: max_coord.x().into(),
+                y: max_coord.y().into(),
             },
             3 => Coord2D {
-                x: max_coord.x(),
-                y: min_coord.y(),
+                x: max_coord.x().into(),
+                y: min_coord.y().into(),
             },
             4 => Coord2D {
-                x: min_coord.x(),
-                y: min_coord.y(),
+                x: min_coord.x().into(),
+                y: min_coord.y().into(),
             },
             _ => unreachable!(),
         }
     }
 }
 
-impl<G: RectTrait<T = f64>> PolygonTrait for RectWrapper<'_, G> {
+impl<G: RectTrait<T: Into<f64>>> PolygonTrait for RectWrapper<'_, G> {
     type T = f64;
     type RingType<'b>
         = &'b RectWrapper<'b, G>
@@ -110,15 +111,15 @@ impl<G: RectTrait<T = f64>> PolygonTrait for RectWrapper<'_, G> {
 }
 
 /// The number of bytes this Rect will take up when encoded as WKB
-pub fn rect_wkb_size(geom: &impl RectTrait<T = f64>) -> usize {
-    polygon_wkb_size(&RectWrapper(geom))
+pub fn rect_wkb_size(geom: &impl RectTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    polygon_wkb_size(&RectWrapper(geom), options)
 }
 
 /// Write a Rect geometry to a Writer encoded as WKB
 pub fn write_rect(
     writer: &mut impl Write,
-    geom: &impl RectTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl RectTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    write_polygon(writer, &RectWrapper(geom), endianness)
+    write_polygon(writer, &RectWrapper(geom), options)
 }
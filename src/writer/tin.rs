@@ -0,0 +1,38 @@
+use crate::common::WKBType;
+use crate::error::{WKBError, WKBResult};
+use crate::writer::multi_polygon_wkb_size;
+use crate::writer::multipolygon::write_multi_polygon_with_types;
+use crate::writer::options::WriteOptions;
+use geo_traits::{LineStringTrait, MultiPolygonTrait, PolygonTrait};
+use std::io::Write;
+
+/// The number of bytes this TIN will take up when encoded as WKB
+pub fn tin_wkb_size(geom: &impl MultiPolygonTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    multi_polygon_wkb_size(geom, options)
+}
+
+/// Write a TIN geometry to a Writer encoded as WKB
+///
+/// Each polygon of `geom` is written as a Triangle patch of the TIN, so each must be empty or have
+/// a single closed ring of four points.
+pub fn write_tin(
+    writer: &mut impl Write,
+    geom: &impl MultiPolygonTrait<T: Into<f64>>,
+    options: WriteOptions,
+) -> WKBResult<()> {
+    if !geom.polygons().all(|polygon| is_triangle(&polygon)) {
+        return Err(WKBError::IncorrectType(
+            "Each polygon of a TIN must be a triangle with one ring of four points".into(),
+        ));
+    }
+
+    write_multi_polygon_with_types(writer, geom, WKBType::Tin, WKBType::Triangle, options)
+}
+
+/// Whether a polygon is empty or has a single ring of four points
+fn is_triangle(polygon: &impl PolygonTrait) -> bool {
+    match polygon.exterior() {
+        Some(ring) => ring.num_coords() == 4 && polygon.num_interiors() == 0,
+        None => true,
+    }
+}
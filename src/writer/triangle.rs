@@ -3,14 +3,15 @@ use std::io::Write;
 use geo_traits::{LineStringTrait, PolygonTrait, TriangleTrait};
 
 use crate::error::WKBResult;
-use crate::writer::{polygon_wkb_size, write_polygon};
-use crate::Endianness;
+use crate::writer::options::WriteOptions;
+use crate::writer::polygon::write_polygon_with_type;
+use crate::writer::polygon_wkb_size;
 
 /// A wrapper around an impl TriangleTrait to provide LineStringTrait and PolygonTrait
-struct TriangleWrapper<'a, G: TriangleTrait<T = f64>>(&'a G);
+pub(crate) struct TriangleWrapper<'a, G: TriangleTrait<T: Into<f64>>>(pub(crate) &'a G);
 
-impl<'a, G: TriangleTrait<T = f64>> LineStringTrait for &'a TriangleWrapper<'a, G> {
-    type T = f64;
+impl<'a, G: TriangleTrait<T: Into<f64>>> LineStringTrait for &'a TriangleWrapper<'a, G> {
+    type T = G::T;
     type CoordType<'b>
         = G::CoordType<'a>
     where
@@ -22,12 +23,13 @@ impl<'a, G: TriangleTrait<T = f64>> LineStringTrait for &'a TriangleWrapper<'a,
     }
 
     fn num_coords(&self) -> usize {
-        3
+        4
     }
 
     unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        // The ring is closed by repeating the first coordinate
         match i {
-            0 => self.0.first(),
+            0 | 3 => self.0.first(),
             1 => self.0.second(),
             2 => self.0.third(),
             _ => unreachable!(),
@@ -35,8 +37,8 @@ impl<'a, G: TriangleTrait<T = f64>> LineStringTrait for &'a TriangleWrapper<'a,
     }
 }
 
-impl<G: TriangleTrait<T = f64>> PolygonTrait for TriangleWrapper<'_, G> {
-    type T = f64;
+impl<G: TriangleTrait<T: Into<f64>>> PolygonTrait for TriangleWrapper<'_, G> {
+    type T = G::T;
     type RingType<'b>
         = &'b TriangleWrapper<'b, G>
     where
@@ -61,15 +63,19 @@ impl<G: TriangleTrait<T = f64>> PolygonTrait for TriangleWrapper<'_, G> {
 }
 
 /// The number of bytes this Triangle will take up when encoded as WKB
-pub fn triangle_wkb_size(geom: &impl TriangleTrait<T = f64>) -> usize {
-    polygon_wkb_size(&TriangleWrapper(geom))
+pub fn triangle_wkb_size(geom: &impl TriangleTrait<T: Into<f64>>, options: WriteOptions) -> usize {
+    polygon_wkb_size(&TriangleWrapper(geom), options)
 }
 
 /// Write a Triangle geometry to a Writer encoded as WKB
+///
+/// The Triangle is written with a single closed ring of four points, as an ISO WKB Triangle or, if
+/// `options` require it, as a Polygon.
 pub fn write_triangle(
     writer: &mut impl Write,
-    geom: &impl TriangleTrait<T = f64>,
-    endianness: Endianness,
+    geom: &impl TriangleTrait<T: Into<f64>>,
+    options: WriteOptions,
 ) -> WKBResult<()> {
-    write_polygon(writer, &TriangleWrapper(geom), endianness)
+    let wkb_type = options.triangle_type();
+    write_polygon_with_type(writer, &TriangleWrapper(geom), wkb_type, options)
 }
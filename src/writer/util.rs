@@ -0,0 +1,48 @@
+use std::io::Write;
+
+use byteorder::{ByteOrder, WriteBytesExt};
+
+use crate::common::{WKBFlavor, WKBType};
+use crate::error::{WKBError, WKBResult};
+
+/// The number of bytes in the byte order, geometry code and any SRID of a geometry
+pub(crate) fn header_wkb_size(flavor: WKBFlavor) -> usize {
+    // - 1: byteOrder
+    // - 4: wkbType
+    // - 4: SRID, if any
+    if flavor.srid().is_some() {
+        1 + 4 + 4
+    } else {
+        1 + 4
+    }
+}
+
+/// Write the geometry code for the given type and flavor, followed by any SRID
+pub(crate) fn write_geometry_code<B: ByteOrder>(
+    writer: &mut impl Write,
+    wkb_type: WKBType,
+    flavor: WKBFlavor,
+) -> WKBResult<()> {
+    match flavor {
+        WKBFlavor::Iso => {
+            writer.write_u32::<B>(wkb_type.into())?;
+        }
+        WKBFlavor::Extended { srid } => {
+            let code = wkb_type.as_ewkb_geometry_code(srid.is_some());
+            writer.write_u32::<B>(code.into())?;
+            if let Some(srid) = srid {
+                writer.write_i32::<B>(srid)?;
+            }
+        }
+        WKBFlavor::Ogc11 => {
+            let code = wkb_type.as_ogc11_geometry_code().ok_or_else(|| {
+                WKBError::IncorrectType(
+                    format!("{:?} cannot be written as OGC 1.1 WKB", wkb_type).into(),
+                )
+            })?;
+            writer.write_u32::<B>(code.into())?;
+        }
+    }
+
+    Ok(())
+}